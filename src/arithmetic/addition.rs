@@ -1,11 +1,40 @@
 use crate::{
-    B0, Bit, BitAnd, BitOr, BitXor, Bitstring, Or, Tape,
+    And, B0, B1, Bit, BitAnd, BitOr, BitXor, Bitstring, GetBit, Or, Shl, Shr, Tape, Xor,
     bits::IfB0,
     conditionals::bitstring::{Lazy, Thunk},
 };
 
 /// Returns the sum of the two given bitstrings.
 pub type Sum<A /*: Bytes*/, B /*: Bytes*/> = <A as Add>::Sum<B>;
+/// Returns the sum of the two given bitstrings and the given carry-in bit. This can be used with
+/// [`CarryOut`] to chain additions together, building arithmetic over wider values out of several
+/// narrower "words".
+pub type AddWithCarryIn<A /*: Bytes*/, B /*: Bytes*/, Cin /*: Bit*/> =
+    <<A as Add>::SumWithCarry<B, Cin> as Bitstring>::Trimmed;
+/// Returns the carry-out bit of adding the two given bitstrings and the given carry-in bit, where
+/// both bitstrings are treated as words of `Width` bits. In other words, this is the bit at index
+/// `Width` of [`AddWithCarryIn<A, B, Cin>`], and will be meaningless if either of `A` or `B` has
+/// bits set past `Width`.
+pub type CarryOut<
+    A,     /*: Bytes*/
+    B,     /*: Bytes*/
+    Cin,   /*: Bit*/
+    Width, /*: Bytes*/
+> = GetBit<AddWithCarryIn<A, B, Cin>, Width>;
+
+/// A 3:2 compressor (carry-save adder), which reduces the three given bitstrings to a
+/// [`Pair`](crate::Pair) of a partial sum and a carry, such that `A + B + C = Sum + Carry`. This
+/// is computed purely with bitwise gates, with no carry propagation, so it's useful for summing
+/// many operands at once, with a single full addition at the end.
+pub type CarrySave<A /*: Bytes*/, B /*: Bytes*/, C /*: Bytes*/> = (
+    Xor<Xor<A, B>, C>,
+    Shl<Or<Or<And<A, B>, And<A, C>>, And<B, C>>, B1>,
+);
+
+/// Returns the average of the two given bitstrings, rounded down, which is `(A + B) >> 1`. This is
+/// computed as `(A & B) + ((A ^ B) >> 1)`, so no intermediate value is ever wider than the wider
+/// of `A` and `B`, meaning it can't overflow a fixed width.
+pub type Avg<A /*: Bytes*/, B /*: Bytes*/> = Sum<And<A, B>, Shr<Xor<A, B>, B1>>;
 
 /// A trait for bitstrings that can be added to other bitstrings. This is implemented for all
 /// bitstrings, and provides methods for adding with all other bitstrings, eliminating the need for
@@ -79,3 +108,104 @@ fn add() {
     assert_eq!(Sum::<T110, T10>::render(), "1000");
     assert_eq!(Sum::<T101, B1>::render(), "110");
 }
+
+#[test]
+fn add_with_carry() {
+    use crate::{B1, Truncate, bs};
+
+    type Width = bs!(1, 0, 0);
+
+    assert_eq!(AddWithCarryIn::<bs!(1, 0), bs!(1, 1), B1>::render(), "110");
+    assert_eq!(AddWithCarryIn::<bs!(1, 0), bs!(1, 1), B0>::render(), "101");
+    assert_eq!(
+        <CarryOut<bs!(1, 1, 1, 1), B0, B1, Width> as Bit>::RENDER,
+        "1"
+    );
+    assert_eq!(
+        <CarryOut<bs!(1, 1, 1, 0), B0, B1, Width> as Bit>::RENDER,
+        "0"
+    );
+
+    // Emulate an 8-bit addition with two 4-bit words: 0b1011_1110 + 0b0110_0011
+    type LowA = bs!(1, 1, 1, 0);
+    type LowB = bs!(0, 0, 1, 1);
+    type HighA = bs!(1, 0, 1, 1);
+    type HighB = bs!(0, 1, 1, 0);
+
+    type Low = Truncate<AddWithCarryIn<LowA, LowB, B0>, Width>;
+    type LowCarry = CarryOut<LowA, LowB, B0, Width>;
+    type High = Truncate<AddWithCarryIn<HighA, HighB, LowCarry>, Width>;
+    type HighCarry = CarryOut<HighA, HighB, LowCarry, Width>;
+
+    assert_eq!(Low::render(), "1");
+    assert_eq!(High::render(), "10");
+    assert_eq!(<HighCarry as Bit>::RENDER, "1");
+    assert_eq!(
+        Sum::<bs!(1, 0, 1, 1, 1, 1, 1, 0), bs!(0, 1, 1, 0, 0, 0, 1, 1)>::render(),
+        "100100001"
+    );
+}
+
+#[test]
+fn carry_save() {
+    use crate::{First, Second, bs};
+
+    // Adding the two outputs should give the sum of all three inputs
+    type Folded<A, B, C> = Sum<First<CarrySave<A, B, C>>, Second<CarrySave<A, B, C>>>;
+
+    assert_eq!(<Folded<B0, B0, B0> as Bitstring>::UNSIGNED, 0);
+    assert_eq!(<Folded<B1, B1, B1> as Bitstring>::UNSIGNED, 3);
+    assert_eq!(
+        <Folded<bs!(1, 0, 1), bs!(1, 1), B1> as Bitstring>::UNSIGNED,
+        9
+    );
+    assert_eq!(
+        <Folded<bs!(1, 1, 1, 1), bs!(1, 1, 1, 1), bs!(1, 1, 1, 1)> as Bitstring>::UNSIGNED,
+        45
+    );
+    assert_eq!(
+        <Folded<bs!(1, 0, 0, 1, 0, 1), B0, bs!(0, 1, 1, 0)> as Bitstring>::UNSIGNED,
+        43
+    );
+    assert_eq!(
+        <Folded<bs!(1, 1, 0), bs!(1, 0, 1, 1, 0, 1), bs!(1, 1, 1)> as Bitstring>::UNSIGNED,
+        58
+    );
+
+    assert_eq!(
+        First::<CarrySave<bs!(1, 0, 1), bs!(1, 1), B1>>::render(),
+        "111"
+    );
+    assert_eq!(
+        Second::<CarrySave<bs!(1, 0, 1), bs!(1, 1), B1>>::render(),
+        "10"
+    );
+}
+
+#[test]
+fn avg() {
+    use crate::{BitLength, Truncate, WrappingAdd, bs};
+
+    assert_eq!(Avg::<B0, B0>::render(), "0");
+    assert_eq!(Avg::<B1, B0>::render(), "0");
+    // Rounds down
+    assert_eq!(Avg::<bs!(1, 0, 1), bs!(1, 1)>::render(), "100");
+    assert_eq!(Avg::<bs!(1, 1, 0, 1), bs!(1, 0, 0, 1, 0)>::render(), "1111");
+    assert_eq!(
+        <Avg<bs!(1, 1, 1, 1, 1, 1, 1, 1), bs!(1, 1, 1, 1, 1, 1, 1, 0)> as Bitstring>::UNSIGNED,
+        254
+    );
+
+    assert_eq!(Avg::<bs!(1, 1, 1, 1), bs!(1, 1, 1, 1)>::render(), "1111");
+    assert_eq!(Avg::<bs!(1, 0, 0), bs!(1, 1, 0)>::render(), "101");
+
+    // The naive midpoint overflows a 4-bit width with near-max operands, but the average doesn't
+    type Width = bs!(1, 0, 0);
+    type A = bs!(1, 1, 1, 1);
+    type B = bs!(1, 1, 0, 1);
+    assert_eq!(Shr::<WrappingAdd<A, B, Width>, B1>::render(), "110");
+    assert_eq!(Avg::<A, B>::render(), "1110");
+    assert_eq!(Truncate::<Avg<A, B>, Width>::render(), "1110");
+    assert_eq!(<BitLength<Avg<A, B>> as Bitstring>::UNSIGNED, 4);
+    assert_eq!(<BitLength<Sum<A, B>> as Bitstring>::UNSIGNED, 5);
+}
@@ -0,0 +1,193 @@
+use super::subtraction::HalfSubtract;
+use crate::{
+    B0, B1, Bit, BitAnd, BitNot, BitXnor, Bitstring, Mux, Or,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+use std::cmp::Ordering;
+
+/// Returns [`B1`](crate::B1) if `A < B`, and [`B0`] otherwise.
+pub type Lt<A /*: Bytes*/, B /*: Bytes*/> = <A as Compare>::Lt<B>;
+/// Returns [`B1`](crate::B1) if `A > B`, and [`B0`] otherwise.
+pub type Gt<A /*: Bytes*/, B /*: Bytes*/> = Lt<B, A>;
+/// Returns [`B1`](crate::B1) if `A <= B`, and [`B0`] otherwise.
+pub type Le<A /*: Bytes*/, B /*: Bytes*/> = BitNot<Gt<A, B>>;
+/// Returns [`B1`](crate::B1) if `A >= B`, and [`B0`] otherwise.
+pub type Ge<A /*: Bytes*/, B /*: Bytes*/> = BitNot<Lt<A, B>>;
+/// Returns [`B1`](crate::B1) if `A` and `B` represent the same value, and [`B0`] otherwise. This
+/// ignores leading zeroes, so `bs!(0, 1)` and `B1` have the same value, even though they're
+/// different types.
+pub type SameValue<A /*: Bytes*/, B /*: Bytes*/> = <A as Compare>::SameValue<B>;
+/// Returns the larger of the two given bitstrings (trimmed). See also
+/// [`max_bits!`](crate::max_bits) for the maximum of more than two bitstrings.
+pub type Max<A /*: Bytes*/, B /*: Bytes*/> =
+    Mux<Lt<A, B>, <A as Bitstring>::Trimmed, <B as Bitstring>::Trimmed>;
+/// Returns the smaller of the two given bitstrings (trimmed). See also
+/// [`min_bits!`](crate::min_bits) for the minimum of more than two bitstrings.
+pub type Min<A /*: Bytes*/, B /*: Bytes*/> =
+    Mux<Lt<A, B>, <B as Bitstring>::Trimmed, <A as Bitstring>::Trimmed>;
+
+/// Returns whether or not the two given bitstrings have the same value, ignoring leading zeroes.
+/// This is a `const` mirror of [`SameValue`], and it's computed at the type level, so it works
+/// even for bitstrings too long for their values to fit in a `usize`.
+pub const fn values_equal<A: Bitstring, B: Bitstring>() -> bool {
+    <SameValue<A, B> as Bit>::BIT
+}
+
+/// A trait for bitstrings that can be compared with other bitstrings. This is implemented for all
+/// bitstrings, and compares their values (i.e. leading zeroes are ignored).
+pub trait Compare: Bitstring {
+    /// Whether or not this bitstring is less than the given one.
+    type Lt<Rhs: Bitstring>: Bit;
+    /// Whether or not this bitstring has the same value as the given one.
+    type SameValue<Rhs: Bitstring>: Bit;
+
+    /// An internal associated type that determines whether or not this bitstring is less than the
+    /// given one, given whether or not the less significant bits already compared were less. This
+    /// is exactly the borrow chain of subtraction: `A < B` if and only if `A - B` borrows out of
+    /// its most significant bit.
+    type LtWithBorrow<Rhs: Bitstring, BorrowIn: Bit>: Bit;
+}
+impl<B: Bitstring> Compare for B {
+    type Lt<Rhs: Bitstring> = Self::LtWithBorrow<Rhs, B0>;
+    type SameValue<Rhs: Bitstring> = <IfB0<
+        // If *both* the head bits are zero, we've reached the final bit (in LSB)
+        Or<Self::Head, Rhs::Head>,
+        // In that case, just compare the final bits
+        Thunk<BitXnor<Self::Lsb, Rhs::Lsb>>,
+        // Otherwise, compare these bits and recurse
+        SameValueRecurse<Self, Rhs>,
+    > as Bitstring>::Lsb;
+
+    type LtWithBorrow<Rhs: Bitstring, BorrowIn: Bit> = <IfB0<
+        Or<Self::Head, Rhs::Head>,
+        // The final borrow out tells us whether or not we're less
+        Thunk<<Self::Lsb as HalfSubtract>::Borrow<Rhs::Lsb, BorrowIn>>,
+        LtRecurse<Self, Rhs, BorrowIn>,
+    > as Bitstring>::Lsb;
+}
+
+/// An internal recursion type for comparing two bitstrings. You shouldn't need to interact with
+/// this as an end user.
+pub struct LtRecurse<A: Bitstring, B: Bitstring, BorrowIn: Bit> {
+    _phantom: ::std::marker::PhantomData<(A, B, BorrowIn)>,
+}
+impl<A: Bitstring, B: Bitstring, BorrowIn: Bit> Lazy for LtRecurse<A, B, BorrowIn> {
+    type Output = <A::Head as Compare>::LtWithBorrow<
+        B::Head,
+        <A::Lsb as HalfSubtract>::Borrow<B::Lsb, BorrowIn>,
+    >;
+}
+
+/// An internal recursion type for checking the equality of two bitstrings. You shouldn't need to
+/// interact with this as an end user.
+pub struct SameValueRecurse<A: Bitstring, B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(A, B)>,
+}
+impl<A: Bitstring, B: Bitstring> Lazy for SameValueRecurse<A, B> {
+    type Output = BitAnd<BitXnor<A::Lsb, B::Lsb>, <A::Head as Compare>::SameValue<B::Head>>;
+}
+
+// Bitstrings are zero-sized, so we can compare instances of them too, with the result being
+// determined entirely by their types
+macro_rules! impl_comparison_ops {
+    ($([$($generics:tt)*] $ty:ty),+) => {
+        $(
+            impl<$($generics)* Rhs: Bitstring> PartialEq<Rhs> for $ty {
+                fn eq(&self, _other: &Rhs) -> bool {
+                    <SameValue<Self, Rhs> as Bit>::UNSIGNED == 1
+                }
+            }
+            impl<$($generics)*> Eq for $ty {}
+            impl<$($generics)* Rhs: Bitstring> PartialOrd<Rhs> for $ty {
+                fn partial_cmp(&self, _other: &Rhs) -> Option<Ordering> {
+                    if <Lt<Self, Rhs> as Bit>::UNSIGNED == 1 {
+                        Some(Ordering::Less)
+                    } else if <SameValue<Self, Rhs> as Bit>::UNSIGNED == 1 {
+                        Some(Ordering::Equal)
+                    } else {
+                        Some(Ordering::Greater)
+                    }
+                }
+            }
+            impl<$($generics)*> Ord for $ty {
+                fn cmp(&self, _other: &Self) -> Ordering {
+                    // Any two instances of the same bitstring type have the same value
+                    Ordering::Equal
+                }
+            }
+        )+
+    };
+}
+impl_comparison_ops!([] B0, [] B1, [H: Bitstring, L: Bit,] crate::Tape<H, L>);
+
+#[test]
+fn compare() {
+    use crate::bs;
+
+    type T5 = bs!(1, 0, 1);
+    type T6 = bs!(1, 1, 0);
+    type T5Untrimmed = bs!(0, 0, 1, 0, 1);
+
+    assert_eq!(Lt::<T5, T6>::render(), "1");
+    assert_eq!(Lt::<T6, T5>::render(), "0");
+    assert_eq!(Lt::<T5, T5Untrimmed>::render(), "0");
+    assert_eq!(Lt::<B1, T5>::render(), "1");
+    assert_eq!(Lt::<B0, B0>::render(), "0");
+    assert_eq!(Gt::<T6, T5Untrimmed>::render(), "1");
+    assert_eq!(Le::<T5, T5Untrimmed>::render(), "1");
+    assert_eq!(Le::<T6, T5>::render(), "0");
+    assert_eq!(Ge::<T5, T6>::render(), "0");
+    assert_eq!(Ge::<T6, B1>::render(), "1");
+
+    assert_eq!(SameValue::<T5, T5Untrimmed>::render(), "1");
+    assert_eq!(SameValue::<T5Untrimmed, T5>::render(), "1");
+    assert_eq!(SameValue::<T5, T6>::render(), "0");
+    assert_eq!(SameValue::<B1, bs!(0, 1)>::render(), "1");
+    assert_eq!(SameValue::<B0, bs!(0, 0)>::render(), "1");
+    assert_eq!(SameValue::<B0, bs!(1, 0)>::render(), "0");
+}
+
+#[test]
+fn max_min() {
+    use crate::bs;
+
+    assert_eq!(Max::<bs!(1, 0), bs!(1, 1, 1)>::render(), "111");
+    assert_eq!(Max::<bs!(1, 1, 1), bs!(1, 0)>::render(), "111");
+    assert_eq!(Min::<bs!(1, 0), bs!(1, 1, 1)>::render(), "10");
+    assert_eq!(Min::<bs!(1, 1, 1), bs!(1, 0)>::render(), "10");
+    assert_eq!(Max::<bs!(0, 1, 1), bs!(1, 1)>::render(), "11");
+    assert_eq!(Min::<B0, bs!(0, 0)>::render(), "0");
+}
+
+#[test]
+fn values_equal_const() {
+    use crate::bs;
+
+    const { assert!(values_equal::<bs!(1, 0, 1), bs!(0, 0, 1, 0, 1)>()) };
+    const { assert!(values_equal::<B0, bs!(0, 0)>()) };
+    const { assert!(!values_equal::<bs!(1, 0, 1), bs!(1, 1, 0)>()) };
+    const { assert!(!values_equal::<B0, B1>()) };
+}
+
+#[test]
+fn compare_instances() {
+    use crate::bs;
+
+    let two = <bs!(1, 0)>::default();
+    let two_untrimmed = <bs!(0, 1, 0)>::default();
+    let five = <bs!(1, 0, 1)>::default();
+
+    assert!(two < five);
+    assert!(five > two);
+    assert!(two <= two_untrimmed);
+    assert!(two >= two_untrimmed);
+    assert!(two == two_untrimmed);
+    assert!(two != five);
+    assert!(B0 < B1);
+    assert!(B1 == <bs!(0, 1)>::default());
+    assert_eq!(two.partial_cmp(&five), Some(Ordering::Less));
+    assert_eq!(five.partial_cmp(&two_untrimmed), Some(Ordering::Greater));
+    assert_eq!(two.partial_cmp(&two_untrimmed), Some(Ordering::Equal));
+    assert_eq!(five.cmp(&five), Ordering::Equal);
+}
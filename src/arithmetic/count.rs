@@ -0,0 +1,147 @@
+use crate::{
+    B0, B1, BitNot, Bitstring, Dec, Ge, SameValue, Sum,
+    bits::{IfB0, IsB0},
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the number of significant bits in the given bitstring, i.e. its length once trimmed of
+/// leading zeroes. The bit length of zero is zero.
+pub type BitLength<B /*: Bytes*/> = <B as Count>::BitLength;
+/// Returns the most significant set bit of the given bitstring, which is [`B1`] for every nonzero
+/// bitstring. Zero has no set bits, so this is [`B0`] for zero (so it doubles as an "is nonzero"
+/// check).
+pub type Msb<B /*: Bytes*/> = BitNot<SameValue<B, B0>>;
+/// Returns the index of the most significant set bit of the given bitstring (counting from the
+/// least-significant bit), which is one less than its [`BitLength`]. Zero has no set bits, so
+/// this is meaningless for zero: decrementing a zero bit length wraps around (see [`Dec`]), giving
+/// one, which is indistinguishable from the index for `10`. Check [`Msb`] first if the value might
+/// be zero.
+pub type MsbIndex<B /*: Bytes*/> = Dec<BitLength<B>>;
+
+/// Returns the number of ones in the given bitstring (its population count, or Hamming weight).
+pub type PopCount<B /*: Bytes*/> = <B as Count>::PopCount;
+/// Returns [`B1`] if the Hamming weight of `B` (see [`PopCount`]) is at least `K`, and [`B0`]
+/// otherwise.
+pub type HammingWeightAtLeast<B /*: Bytes*/, K /*: Bytes*/> = Ge<PopCount<B>, K>;
+
+/// Returns the number of ones in the given bitstring, like [`PopCount`], but as a value directly,
+/// which avoids instantiating the intermediate sums at the type level.
+pub const fn popcount<B: Bitstring>() -> usize {
+    // Any bitstring whose value is zero has no more ones (and a zero head, so we'd loop forever).
+    // We don't check the value directly, so this works for bitstrings too wide for a `usize`.
+    if <<B::Trimmed as IsB0>::GlobalIsB0 as crate::Boolean>::BOOL {
+        0
+    } else {
+        <B::Lsb as crate::Bit>::BIT as usize + popcount::<B::Head>()
+    }
+}
+
+/// A trait for counting properties of bitstrings. This is implemented for all bitstrings.
+pub trait Count: Bitstring {
+    /// The number of significant bits in this bitstring.
+    type BitLength: Bitstring;
+    /// The number of ones in this bitstring.
+    type PopCount: Bitstring;
+}
+impl<B: Bitstring> Count for B {
+    // Once we're left with only leading zeroes, there's nothing more to count
+    type BitLength = IfB0<B::Trimmed, Thunk<B0>, BitLengthRecurse<B>>;
+    type PopCount = IfB0<B::Trimmed, Thunk<B0>, PopCountRecurse<B>>;
+}
+
+/// An internal recursion type for counting the significant bits of a bitstring. You shouldn't need
+/// to interact with this as an end user.
+pub struct BitLengthRecurse<B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<B>,
+}
+impl<B: Bitstring> Lazy for BitLengthRecurse<B> {
+    type Output = Sum<<B::Head as Count>::BitLength, B1>;
+}
+
+/// An internal recursion type for counting the ones in a bitstring. You shouldn't need to interact
+/// with this as an end user.
+pub struct PopCountRecurse<B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<B>,
+}
+impl<B: Bitstring> Lazy for PopCountRecurse<B> {
+    type Output = Sum<<B::Head as Count>::PopCount, B::Lsb>;
+}
+
+#[test]
+fn count() {
+    use crate::bs;
+
+    assert_eq!(BitLength::<B0>::UNSIGNED, 0);
+    assert_eq!(BitLength::<bs!(0, 0)>::UNSIGNED, 0);
+    assert_eq!(BitLength::<B1>::UNSIGNED, 1);
+    assert_eq!(BitLength::<bs!(0, 0, 1)>::UNSIGNED, 1);
+    assert_eq!(BitLength::<bs!(1, 0, 1)>::UNSIGNED, 3);
+    assert_eq!(BitLength::<bs!(0, 1, 0, 0, 0)>::UNSIGNED, 4);
+}
+
+#[test]
+fn msb() {
+    use crate::{Bit, bs};
+
+    assert_eq!(<Msb<bs!(1, 0, 1)> as Bit>::RENDER, "1");
+    assert_eq!(<Msb<bs!(0, 0, 1)> as Bit>::RENDER, "1");
+    assert_eq!(<Msb<B1> as Bit>::RENDER, "1");
+    assert_eq!(<Msb<B0> as Bit>::RENDER, "0");
+    assert_eq!(<Msb<bs!(0, 0)> as Bit>::RENDER, "0");
+
+    assert_eq!(MsbIndex::<bs!(1, 0, 1)>::render(), "10");
+    assert_eq!(MsbIndex::<bs!(0, 0, 1, 1)>::render(), "1");
+    assert_eq!(MsbIndex::<B1>::render(), "0");
+    assert_eq!(MsbIndex::<bs!(1, 0, 0, 0, 0, 0, 0, 0, 0)>::UNSIGNED, 8);
+    // Zero wraps around
+    assert_eq!(MsbIndex::<B0>::render(), "1");
+}
+
+#[test]
+fn pop_count() {
+    use crate::bs;
+
+    assert_eq!(PopCount::<B0>::UNSIGNED, 0);
+    assert_eq!(PopCount::<B1>::UNSIGNED, 1);
+    assert_eq!(PopCount::<bs!(0, 1, 0)>::UNSIGNED, 1);
+    assert_eq!(PopCount::<bs!(1, 0, 1, 1)>::UNSIGNED, 3);
+    assert_eq!(PopCount::<bs!(1, 1, 1, 1, 1, 1, 1)>::UNSIGNED, 7);
+
+    type T = bs!(1, 0, 1, 1);
+    assert_eq!(HammingWeightAtLeast::<T, bs!(1, 0)>::render(), "1");
+    assert_eq!(HammingWeightAtLeast::<T, bs!(1, 1)>::render(), "1");
+    assert_eq!(HammingWeightAtLeast::<T, bs!(1, 0, 0)>::render(), "0");
+    assert_eq!(HammingWeightAtLeast::<B0, B0>::render(), "1");
+}
+
+#[test]
+fn popcount_const() {
+    use crate::{Product, Tape, bs};
+
+    assert_eq!(popcount::<B0>(), 0);
+    assert_eq!(popcount::<B1>(), 1);
+    assert_eq!(popcount::<bs!(0, 0, 0)>(), 0);
+    assert_eq!(popcount::<bs!(1, 0, 1, 1, 0)>(), 3);
+    assert_eq!(popcount::<bs!(0, 0, 1, 1, 1, 1, 1)>(), 5);
+    assert_eq!(popcount::<Tape<bs!(1, 1, 1, 1, 1, 1, 1, 1), B0>>(), 8);
+    // 11 * 13 = 143, which is `10001111`
+    assert_eq!(popcount::<Product<bs!(1, 0, 1, 1), bs!(1, 1, 0, 1)>>(), 5);
+    // This should always agree with the type-level count
+    assert_eq!(
+        <PopCount<Product<bs!(1, 0, 1, 1), bs!(1, 1, 0, 1)>> as Bitstring>::UNSIGNED,
+        5
+    );
+    assert_eq!(
+        <PopCount<Tape<bs!(1, 1, 1, 1, 1, 1, 1, 1), B0>> as Bitstring>::UNSIGNED,
+        8
+    );
+
+    const { assert!(popcount::<bs!(1, 1, 0, 1)>() == 3) };
+    // This is too wide to have a `usize` value, but we can still count its ones
+    type Wide = bs!(
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 1
+    );
+    assert_eq!(popcount::<Wide>(), 2);
+}
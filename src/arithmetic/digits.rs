@@ -0,0 +1,109 @@
+use crate::{
+    B0, B1, Bitstring, Lt, Quotient, Sum, Tape,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the number of digits needed to write the value of the given bitstring in base `Base`
+/// (e.g. `bs!(1, 0, 1, 0)` for decimal). Zero is written as a single digit, like any other value
+/// less than `Base`. `Base` must be at least 2, otherwise this will recurse forever.
+pub type DigitCount<B /*: Bytes*/, Base /*: Bytes*/> = <B as Digits>::DigitCount<Base>;
+
+/// Ten, for decimal digits.
+type Ten = Tape<Tape<Tape<B1, B0>, B1>, B0>;
+
+/// Returns the ASCII decimal digits of the value of `B`, most significant first, as an array of
+/// exactly `K` bytes. `K` must be the number of decimal digits in `B` (i.e. `DigitCount<B,
+/// bs!(1, 0, 1, 0)>`), otherwise this will fail to compile. The [`digits!`](crate::digits) macro
+/// works this out for you.
+pub const fn decimal_digits<B: Bitstring, const K: usize>() -> [u8; K] {
+    const {
+        assert!(
+            <DigitCount<B, Ten> as Bitstring>::UNSIGNED == K,
+            "wrong number of digits for bitstring"
+        )
+    };
+
+    let mut digits = [b'0'; K];
+    let mut value = B::UNSIGNED;
+    let mut i = K;
+    // Zero is already written, as we start with all zeroes
+    while value != 0 {
+        i -= 1;
+        digits[i] = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    digits
+}
+
+/// A trait for working with the digits of bitstrings in other bases. This is implemented for all
+/// bitstrings.
+pub trait Digits: Bitstring {
+    /// The number of base-`Base` digits in the value of this bitstring.
+    type DigitCount<Base: Bitstring>: Bitstring;
+}
+impl<B: Bitstring> Digits for B {
+    // Anything less than the base is a single digit
+    type DigitCount<Base: Bitstring> = IfB0<Lt<B, Base>, DigitCountRecurse<B, Base>, Thunk<B1>>;
+}
+
+/// An internal recursion type for counting digits. You shouldn't need to interact with this as an
+/// end user.
+pub struct DigitCountRecurse<B: Bitstring, Base: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(B, Base)>,
+}
+impl<B: Bitstring, Base: Bitstring> Lazy for DigitCountRecurse<B, Base> {
+    // Dividing by the base drops the last digit
+    type Output = Sum<<Quotient<B, Base> as Digits>::DigitCount<Base>, B1>;
+}
+
+#[test]
+fn digit_count() {
+    use crate::bs;
+
+    type Two = bs!(1, 0);
+
+    assert_eq!(
+        DigitCount::<bs!(1, 1, 1, 1, 1, 0, 1, 0), Ten>::render(),
+        "11"
+    );
+    assert_eq!(DigitCount::<B0, Ten>::UNSIGNED, 1);
+    assert_eq!(DigitCount::<bs!(1, 0, 0, 1), Ten>::UNSIGNED, 1);
+    assert_eq!(DigitCount::<Ten, Ten>::UNSIGNED, 2);
+    assert_eq!(DigitCount::<bs!(1, 1, 0, 0, 0, 1, 1), Ten>::UNSIGNED, 2);
+    assert_eq!(DigitCount::<bs!(1, 1, 0, 0, 1, 0, 0), Ten>::UNSIGNED, 3);
+    assert_eq!(
+        DigitCount::<bs!(1, 1, 1, 1, 1, 0, 1, 0, 0, 0), Ten>::UNSIGNED,
+        4
+    );
+
+    // In binary, this is just the bit length (but zero still has a digit)
+    assert_eq!(DigitCount::<B0, Two>::UNSIGNED, 1);
+    assert_eq!(DigitCount::<B1, Two>::UNSIGNED, 1);
+    assert_eq!(DigitCount::<bs!(1, 0), Two>::UNSIGNED, 2);
+    assert_eq!(DigitCount::<bs!(0, 0, 1, 1, 0), Two>::UNSIGNED, 3);
+    assert_eq!(DigitCount::<bs!(1, 0, 0, 0, 0, 0, 0), Two>::UNSIGNED, 7);
+
+    // Hexadecimal
+    assert_eq!(
+        DigitCount::<bs!(1, 1, 1, 1), bs!(1, 0, 0, 0, 0)>::UNSIGNED,
+        1
+    );
+    assert_eq!(
+        DigitCount::<bs!(1, 0, 0, 0, 0), bs!(1, 0, 0, 0, 0)>::UNSIGNED,
+        2
+    );
+}
+
+#[test]
+fn decimal_digits_const() {
+    use crate::{bs, digits};
+
+    const ONE_TWO_THREE: [u8; 3] = digits!(bs!(1, 1, 1, 1, 0, 1, 1));
+    assert_eq!(ONE_TWO_THREE, [b'1', b'2', b'3']);
+    assert_eq!(digits!(B0), [b'0']);
+    assert_eq!(digits!(bs!(0, 0)), [b'0']);
+    assert_eq!(digits!(bs!(1, 0, 1, 0)), *b"10");
+    assert_eq!(digits!(bs!(1, 1, 1, 1, 1, 0, 1, 0, 0, 0)), *b"1000");
+    assert_eq!(decimal_digits::<bs!(1, 0, 0, 1), 1>(), [b'9']);
+}
@@ -0,0 +1,156 @@
+use crate::{
+    B0, Bit, BitNot, Bitstring, Diff, Ge, SameValue, Sum, Tape,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the quotient of the two given bitstrings, rounding down. See [`Divide`] for how this
+/// handles division by zero.
+pub type Quotient<A /*: Bytes*/, B /*: Bytes*/> = <A as Divide>::Quotient<B>;
+/// Returns the quotient of the two given bitstrings, rounding up, which is `(A + B - 1) / B` for
+/// non-zero `B`. This is useful for working out how many tiles of size `B` are needed to cover `A`.
+/// See [`Divide`] for how this handles division by zero.
+pub type CeilDiv<A /*: Bytes*/, B /*: Bytes*/> = <A as Divide>::CeilQuotient<B>;
+/// Returns the remainder of dividing the first given bitstring by the second. See [`Divide`] for
+/// how this handles division by zero.
+pub type Remainder<A /*: Bytes*/, B /*: Bytes*/> = <A as Divide>::Remainder<B>;
+/// Returns [`B1`](crate::B1) if `A` is a multiple of `B` (i.e. dividing `A` by `B` leaves no
+/// remainder), and [`B0`] otherwise. Zero is the only multiple of zero, so this is [`B1`] for a
+/// divisor of zero only when `A` is also zero.
+pub type IsMultipleOf<A /*: Bytes*/, B /*: Bytes*/> = SameValue<Remainder<A, B>, B0>;
+
+/// Returns whether or not `A` is a multiple of `B`. This is a `const` mirror of [`IsMultipleOf`].
+pub const fn is_multiple_of<A: Bitstring, B: Bitstring>() -> bool {
+    <IsMultipleOf<A, B> as Bit>::BIT
+}
+
+/// A trait for bitstrings that can be divided by other bitstrings. This is implemented for all
+/// bitstrings, using long division over the bits of the dividend.
+///
+/// Division by zero doesn't fail to compile, but rather behaves as if every bit of the quotient
+/// "fit": the remainder will be the dividend itself, and the quotient will be all ones, as long as
+/// the dividend's bit length. As the remainder is non-zero (unless the dividend is zero), the
+/// rounded-up quotient will be one more than that.
+pub trait Divide: Bitstring {
+    /// The quotient of this bitstring divided by the given one.
+    type Quotient<Rhs: Bitstring>: Bitstring;
+    /// The remainder of this bitstring divided by the given one.
+    type Remainder<Rhs: Bitstring>: Bitstring;
+    /// The quotient of this bitstring divided by the given one, rounded up.
+    type CeilQuotient<Rhs: Bitstring>: Bitstring;
+}
+impl<B: Bitstring> Divide for B {
+    // Zero divided by anything is zero with no remainder, which is also our base case
+    type Quotient<Rhs: Bitstring> = IfB0<B::Trimmed, Thunk<B0>, QuotientRecurse<Self, Rhs>>;
+    type Remainder<Rhs: Bitstring> = IfB0<B::Trimmed, Thunk<B0>, RemainderRecurse<Self, Rhs>>;
+    // Rather than computing `(A + B - 1) / B`, which would underflow for a zero dividend, we round
+    // up whenever there's a remainder
+    type CeilQuotient<Rhs: Bitstring> =
+        Sum<Self::Quotient<Rhs>, BitNot<SameValue<Self::Remainder<Rhs>, B0>>>;
+}
+
+/// An internal recursion type for computing quotients. You shouldn't need to interact with this as
+/// an end user.
+pub struct QuotientRecurse<A: Bitstring, B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(A, B)>,
+}
+impl<A: Bitstring, B: Bitstring> Lazy for QuotientRecurse<A, B> {
+    // Bring down the next bit of the dividend onto the remainder of dividing the head, and if the
+    // divisor goes into that, this bit of the quotient is a one
+    type Output = <Tape<
+        <A::Head as Divide>::Quotient<B>,
+        Ge<Tape<<A::Head as Divide>::Remainder<B>, A::Lsb>, B>,
+    > as Bitstring>::Trimmed;
+}
+
+/// An internal recursion type for computing remainders. You shouldn't need to interact with this
+/// as an end user.
+pub struct RemainderRecurse<A: Bitstring, B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(A, B)>,
+}
+impl<A: Bitstring, B: Bitstring> Lazy for RemainderRecurse<A, B> {
+    // Bring down the next bit of the dividend onto the remainder of dividing the head, and
+    // subtract the divisor if it goes into that
+    type Output = IfB0<
+        Ge<Tape<<A::Head as Divide>::Remainder<B>, A::Lsb>, B>,
+        Thunk<<Tape<<A::Head as Divide>::Remainder<B>, A::Lsb> as Bitstring>::Trimmed>,
+        Thunk<Diff<Tape<<A::Head as Divide>::Remainder<B>, A::Lsb>, B>>,
+    >;
+}
+
+#[test]
+fn divide() {
+    use crate::{B1, bs};
+
+    assert_eq!(Quotient::<bs!(1, 1, 1), bs!(1, 0)>::render(), "11");
+    assert_eq!(Remainder::<bs!(1, 1, 1), bs!(1, 0)>::render(), "1");
+    assert_eq!(Quotient::<bs!(1, 1, 0, 0), bs!(1, 0, 0)>::render(), "11");
+    assert_eq!(Remainder::<bs!(1, 1, 0, 0), bs!(1, 0, 0)>::render(), "0");
+    assert_eq!(
+        Quotient::<bs!(1, 1, 1, 1, 1, 0, 1, 0), bs!(1, 0, 1, 0)>::render(),
+        "11001"
+    );
+    assert_eq!(
+        Remainder::<bs!(1, 1, 1, 1, 1, 0, 1, 0), bs!(1, 0, 1, 0)>::render(),
+        "0"
+    );
+    assert_eq!(Quotient::<bs!(1, 1), bs!(1, 0, 1)>::render(), "0");
+    assert_eq!(Remainder::<bs!(1, 1), bs!(1, 0, 1)>::render(), "11");
+    assert_eq!(Quotient::<bs!(0, 1, 1, 0), B1>::render(), "110");
+    assert_eq!(Remainder::<bs!(0, 1, 1, 0), B1>::render(), "0");
+    assert_eq!(Quotient::<B0, bs!(1, 1)>::render(), "0");
+
+    // Division by zero
+    assert_eq!(Quotient::<bs!(1, 0, 1), B0>::render(), "111");
+    assert_eq!(Remainder::<bs!(1, 0, 1), B0>::render(), "101");
+}
+
+#[test]
+fn is_multiple_of_check() {
+    use crate::{B1, bs};
+
+    assert_eq!(
+        <IsMultipleOf<bs!(1, 1, 0, 0), bs!(1, 0, 0)> as Bit>::RENDER,
+        "1"
+    );
+    assert_eq!(
+        <IsMultipleOf<bs!(1, 1, 0, 1), bs!(1, 0, 0)> as Bit>::RENDER,
+        "0"
+    );
+    assert_eq!(
+        <IsMultipleOf<bs!(1, 1, 1, 1), bs!(1, 0, 1)> as Bit>::RENDER,
+        "1"
+    );
+    assert_eq!(<IsMultipleOf<bs!(1, 0), bs!(1, 1)> as Bit>::RENDER, "0");
+    assert_eq!(<IsMultipleOf<B0, bs!(1, 1)> as Bit>::RENDER, "1");
+    // Everything is a multiple of one
+    assert_eq!(<IsMultipleOf<bs!(1, 0, 1, 1), B1> as Bit>::RENDER, "1");
+    // Only zero is a multiple of zero
+    assert_eq!(<IsMultipleOf<bs!(1, 0, 1), B0> as Bit>::RENDER, "0");
+    assert_eq!(<IsMultipleOf<bs!(0, 0), B0> as Bit>::RENDER, "1");
+
+    const { assert!(is_multiple_of::<bs!(1, 1, 0, 0), bs!(1, 0, 0)>()) };
+    const { assert!(!is_multiple_of::<bs!(1, 1, 1), bs!(1, 0)>()) };
+}
+
+#[test]
+fn ceil_div() {
+    use crate::{B1, bs};
+
+    assert_eq!(CeilDiv::<bs!(1, 1, 1), bs!(1, 0)>::render(), "100");
+    // Exact
+    assert_eq!(CeilDiv::<bs!(1, 1, 0, 0), bs!(1, 0, 0)>::render(), "11");
+    assert_eq!(CeilDiv::<bs!(1, 1, 1, 1), bs!(1, 0, 1)>::render(), "11");
+    assert_eq!(CeilDiv::<B0, bs!(1, 1)>::render(), "0");
+    // Inexact
+    assert_eq!(CeilDiv::<bs!(1, 1, 0, 1), bs!(1, 0, 0)>::render(), "100");
+    assert_eq!(CeilDiv::<bs!(1, 0), bs!(1, 1)>::render(), "1");
+    assert_eq!(
+        CeilDiv::<bs!(1, 0, 0, 0, 0, 1), bs!(1, 0, 0, 0)>::render(),
+        "101"
+    );
+    // By one
+    assert_eq!(CeilDiv::<bs!(1, 0, 1, 1), B1>::render(), "1011");
+    assert_eq!(CeilDiv::<B1, B1>::render(), "1");
+    assert_eq!(CeilDiv::<B0, B1>::render(), "0");
+}
@@ -0,0 +1,125 @@
+use crate::{
+    B0, B1, Bitstring, Diff, Or, Shl, Shr, Sum, Tape,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the bit at index `I` of the given bitstring, where index 0 is the least-significant
+/// bit. Indices past the most-significant bit give [`B0`].
+pub type GetBit<B /*: Bytes*/, I /*: Bytes*/> = <Shr<B, I> as Bitstring>::Lsb;
+/// Returns the lowest `Width` bits of the given bitstring (trimmed), discarding the rest.
+pub type Truncate<B /*: Bytes*/, Width /*: Bytes*/> = <B as BitField>::Truncated<Width>;
+/// Returns the `Len` bits of the given bitstring starting at index `Start` (trimmed), which is
+/// `(B >> Start) & ((1 << Len) - 1)`. Any of those bits past the most-significant bit of `B` are
+/// zero, like with [`GetBit`].
+pub type Extract<B /*: Bytes*/, Start /*: Bytes*/, Len /*: Bytes*/> = Truncate<Shr<B, Start>, Len>;
+/// Returns `Dst` with its `Len` bits starting at index `Start` replaced by the lowest `Len` bits of
+/// `Field`, leaving every other bit as it was. This is the counterpart of [`Extract`], and the
+/// result is trimmed.
+pub type Insert<
+    Dst,   /*: Bytes*/
+    Field, /*: Bytes*/
+    Start, /*: Bytes*/
+    Len,   /*: Bytes*/
+> = <Or<
+    // Everything from `Dst` except the field
+    Or<Truncate<Dst, Start>, Shl<Shr<Dst, Sum<Start, Len>>, Sum<Start, Len>>>,
+    Shl<Truncate<Field, Len>, Start>,
+> as Bitstring>::Trimmed;
+
+/// A trait for bitstrings that can have fields of bits operated on. This is implemented for all
+/// bitstrings.
+pub trait BitField: Bitstring {
+    /// The lowest `Width` bits of this bitstring.
+    type Truncated<Width: Bitstring>: Bitstring;
+}
+impl<B: Bitstring> BitField for B {
+    // Once we've kept as many bits as we need, the rest are all zero
+    type Truncated<Width: Bitstring> = IfB0<Width::Trimmed, Thunk<B0>, TruncateRecurse<B, Width>>;
+}
+
+/// An internal recursion type for truncating bitstrings. You shouldn't need to interact with this
+/// as an end user.
+pub struct TruncateRecurse<B: Bitstring, Width: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(B, Width)>,
+}
+impl<B: Bitstring, Width: Bitstring> Lazy for TruncateRecurse<B, Width> {
+    type Output =
+        <Tape<<B::Head as BitField>::Truncated<Diff<Width, B1>>, B::Lsb> as Bitstring>::Trimmed;
+}
+
+#[test]
+fn field() {
+    use crate::{Bit, bs};
+
+    type T = bs!(1, 1, 0, 1, 0);
+
+    assert_eq!(<GetBit<T, B0> as Bit>::RENDER, "0");
+    assert_eq!(<GetBit<T, B1> as Bit>::RENDER, "1");
+    assert_eq!(<GetBit<T, bs!(1, 0, 0)> as Bit>::RENDER, "1");
+    assert_eq!(<GetBit<T, bs!(1, 0, 1)> as Bit>::RENDER, "0");
+    assert_eq!(<GetBit<T, bs!(1, 0, 0, 0, 0)> as Bit>::RENDER, "0");
+
+    assert_eq!(Truncate::<T, bs!(1, 1)>::render(), "10");
+    assert_eq!(Truncate::<T, bs!(1, 0, 0)>::render(), "1010");
+    assert_eq!(Truncate::<T, bs!(1, 1, 1)>::render(), "11010");
+    assert_eq!(Truncate::<T, B0>::render(), "0");
+    assert_eq!(Truncate::<bs!(1, 0, 0, 1), bs!(1, 0)>::render(), "1");
+}
+
+#[test]
+fn extract() {
+    use crate::bs;
+
+    type T = bs!(1, 1, 0, 1, 0, 1);
+
+    assert_eq!(Extract::<T, bs!(1, 0), bs!(1, 1)>::render(), "101");
+    // At the least-significant bit
+    assert_eq!(Extract::<T, B0, bs!(1, 1)>::render(), "101");
+    assert_eq!(Extract::<T, B0, B1>::render(), "1");
+    // In the middle
+    assert_eq!(Extract::<T, B1, bs!(1, 0)>::render(), "10");
+    assert_eq!(Extract::<T, bs!(1, 1), bs!(1, 1)>::render(), "110");
+    // Running past the most-significant bit
+    assert_eq!(Extract::<T, bs!(1, 0, 0), bs!(1, 0, 0)>::render(), "11");
+    assert_eq!(Extract::<T, bs!(1, 1, 1), bs!(1, 1)>::render(), "0");
+    assert_eq!(Extract::<T, B1, B0>::render(), "0");
+}
+
+#[test]
+fn insert() {
+    use crate::bs;
+
+    type T = bs!(1, 1, 0, 1, 0, 1);
+
+    // Replace bits 2 and 3 (`01`) with `10`
+    assert_eq!(
+        Insert::<T, bs!(1, 0), bs!(1, 0), bs!(1, 0)>::render(),
+        "111001"
+    );
+    // Only the low bits of the field are used
+    assert_eq!(
+        Insert::<T, bs!(1, 1, 1, 0), bs!(1, 0), bs!(1, 0)>::render(),
+        "111001"
+    );
+    // Bits outside the field are preserved, even when clearing the whole field
+    assert_eq!(Insert::<T, B0, B1, bs!(1, 0, 0)>::render(), "100001");
+    assert_eq!(
+        Insert::<bs!(1, 1, 1, 1, 1, 1, 1, 1), B0, bs!(1, 1), bs!(1, 0)>::render(),
+        "11100111"
+    );
+    // Clearing the top of the word trims the result
+    assert_eq!(Insert::<T, B0, bs!(1, 0, 0), bs!(1, 0)>::render(), "101");
+    // Inserting past the most-significant bit extends the word
+    assert_eq!(
+        Insert::<B1, bs!(1, 1), bs!(1, 0, 0), bs!(1, 0)>::render(),
+        "110001"
+    );
+    assert_eq!(Insert::<T, bs!(1, 1), B0, B0>::render(), "110101");
+
+    // Extracting what we inserted gives it back
+    type Packed = Insert<Insert<B0, bs!(1, 0, 1), B0, bs!(1, 1)>, bs!(1, 1), bs!(1, 1), bs!(1, 0)>;
+    assert_eq!(Packed::render(), "11101");
+    assert_eq!(Extract::<Packed, B0, bs!(1, 1)>::render(), "101");
+    assert_eq!(Extract::<Packed, bs!(1, 1), bs!(1, 0)>::render(), "11");
+}
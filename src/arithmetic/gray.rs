@@ -0,0 +1,164 @@
+use crate::{
+    B0, B1, Bitstring, Shr, Truncate, Xor,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the Gray code encoding of the given bitstring, which is `B ^ (B >> 1)`.
+pub type GrayEncode<B /*: Bytes*/> = <B as GrayCode>::Encoded;
+/// Returns the bitstring whose Gray code encoding is the given bitstring. This is the inverse of
+/// [`GrayEncode`].
+pub type GrayDecode<B /*: Bytes*/> = <B as GrayCode>::Decoded;
+
+/// Returns the `Width`-bit Gray code encoding of the given bitstring. Any bits past `Width` are
+/// ignored, so this is [`GrayEncode`] of the lowest `Width` bits. See also [`binary_to_gray`].
+pub type BinaryToGray<B /*: Bytes*/, Width /*: Bytes*/> = GrayEncode<Truncate<B, Width>>;
+/// Returns the `Width`-bit value whose Gray code encoding is the given bitstring. Any bits past
+/// `Width` are ignored, so this is [`GrayDecode`] of the lowest `Width` bits, and the inverse of
+/// [`BinaryToGray`] at the same width. See also [`gray_to_binary`].
+pub type GrayToBinary<B /*: Bytes*/, Width /*: Bytes*/> = GrayDecode<Truncate<B, Width>>;
+
+/// Returns the lowest `width` bits of `x`.
+const fn truncate(x: usize, width: u32) -> usize {
+    if width >= usize::BITS {
+        x
+    } else {
+        x & ((1 << width) - 1)
+    }
+}
+
+/// Returns the `width`-bit Gray code encoding of `x`, ignoring any bits past `width`. This is a
+/// `const` mirror of [`BinaryToGray`].
+pub const fn binary_to_gray(x: usize, width: u32) -> usize {
+    let x = truncate(x, width);
+    x ^ (x >> 1)
+}
+/// Returns the `width`-bit value whose Gray code encoding is `x`, ignoring any bits past `width`.
+/// This is a `const` mirror of [`GrayToBinary`].
+pub const fn gray_to_binary(x: usize, width: u32) -> usize {
+    let mut x = truncate(x, width);
+    let mut decoded = 0;
+    while x != 0 {
+        decoded ^= x;
+        x >>= 1;
+    }
+    decoded
+}
+
+/// A trait for bitstrings that can be converted to and from (reflected binary) Gray codes. This is
+/// implemented for all bitstrings.
+pub trait GrayCode: Bitstring {
+    /// The Gray code encoding of this bitstring.
+    type Encoded: Bitstring;
+    /// The bitstring whose Gray code encoding is this bitstring.
+    type Decoded: Bitstring;
+}
+impl<B: Bitstring> GrayCode for B {
+    type Encoded = Xor<B, Shr<B, B1>>;
+    // Decoding is `B ^ (B >> 1) ^ (B >> 2) ^ ...`, which we can do by recursing until there's
+    // nothing left to shift
+    type Decoded = IfB0<B::Trimmed, Thunk<B0>, GrayDecodeRecurse<B>>;
+}
+
+/// An internal recursion type for decoding Gray codes. You shouldn't need to interact with this as
+/// an end user.
+pub struct GrayDecodeRecurse<B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<B>,
+}
+impl<B: Bitstring> Lazy for GrayDecodeRecurse<B> {
+    type Output = Xor<B, <Shr<B, B1> as GrayCode>::Decoded>;
+}
+
+#[test]
+fn gray() {
+    use crate::bs;
+
+    assert_eq!(GrayEncode::<bs!(1, 0, 0)>::render(), "110");
+    assert_eq!(GrayEncode::<bs!(1, 1, 1)>::render(), "100");
+    assert_eq!(GrayEncode::<bs!(1, 0, 1, 1)>::render(), "1110");
+    assert_eq!(GrayEncode::<B1>::render(), "1");
+    assert_eq!(GrayEncode::<B0>::render(), "0");
+
+    assert_eq!(GrayDecode::<bs!(1, 1, 0)>::render(), "100");
+    assert_eq!(GrayDecode::<bs!(1, 1, 1, 0)>::render(), "1011");
+
+    macro_rules! assert_round_trip {
+        ($($ty:ty),+) => {
+            $(
+                assert_eq!(GrayDecode::<GrayEncode<$ty>>::render(), <$ty>::render_trimmed());
+            )+
+        };
+    }
+    assert_round_trip!(
+        B0,
+        B1,
+        bs!(1, 0),
+        bs!(1, 1),
+        bs!(1, 0, 1),
+        bs!(1, 1, 0, 1),
+        bs!(1, 0, 0, 1, 0, 1, 1)
+    );
+}
+
+#[test]
+fn gray_width() {
+    use crate::bs;
+
+    type W3 = bs!(1, 1);
+    type W4 = bs!(1, 0, 0);
+
+    assert_eq!(BinaryToGray::<bs!(1, 0, 0), W3>::render(), "110");
+    // Bits past the width are ignored
+    assert_eq!(BinaryToGray::<bs!(1, 1, 0, 0), W3>::render(), "110");
+    assert_eq!(GrayToBinary::<bs!(1, 1, 1, 0), W3>::render(), "100");
+    assert_eq!(BinaryToGray::<bs!(1, 1), B0>::render(), "0");
+
+    assert_eq!(BinaryToGray::<bs!(1, 0, 1), W3>::render(), "111");
+    assert_eq!(GrayToBinary::<bs!(1, 0, 1), W3>::render(), "110");
+    assert_eq!(BinaryToGray::<bs!(1, 1, 0, 1), W3>::render(), "111");
+    assert_eq!(BinaryToGray::<bs!(1, 1, 0, 1), W4>::render(), "1011");
+    assert_eq!(GrayToBinary::<bs!(1, 1, 0, 1), W4>::render(), "1001");
+    assert_eq!(
+        BinaryToGray::<bs!(1, 0, 0, 1, 0, 1, 1), W4>::render(),
+        "1110"
+    );
+    assert_eq!(
+        BinaryToGray::<bs!(1, 0, 0, 1, 0, 1, 1), bs!(1, 1, 1)>::render(),
+        "1101110"
+    );
+
+    // Round trips give the truncated value back
+    assert_eq!(
+        GrayToBinary::<BinaryToGray<bs!(1, 1, 0, 1), W4>, W4>::render(),
+        "1101"
+    );
+    assert_eq!(
+        BinaryToGray::<GrayToBinary<bs!(1, 0, 0, 1, 0, 1, 1), W4>, W4>::render(),
+        "1011"
+    );
+    assert_eq!(GrayToBinary::<BinaryToGray<B1, B1>, B1>::render(), "1");
+
+    // The const mirrors agree with the type-level conversions
+    assert_eq!(binary_to_gray(0b101, 3), 0b111);
+    assert_eq!(gray_to_binary(0b101, 3), 0b110);
+    assert_eq!(binary_to_gray(0b1101, 3), 0b111);
+    assert_eq!(binary_to_gray(0b1101, 4), 0b1011);
+    assert_eq!(gray_to_binary(0b1101, 4), 0b1001);
+    assert_eq!(binary_to_gray(0b1001011, 4), 0b1110);
+    assert_eq!(binary_to_gray(0b1001011, 7), 0b1101110);
+    assert_eq!(gray_to_binary(0b1101110, 7), 0b1001011);
+    assert_eq!(binary_to_gray(0b11, 0), 0);
+    assert_eq!(
+        <BinaryToGray<bs!(1, 1, 0, 1), W4> as Bitstring>::UNSIGNED,
+        binary_to_gray(0b1101, 4)
+    );
+    assert_eq!(
+        <GrayToBinary<bs!(1, 1, 0, 1), W4> as Bitstring>::UNSIGNED,
+        gray_to_binary(0b1101, 4)
+    );
+
+    // Every 3-bit code is reached exactly once, and neighbours differ by one bit
+    let codes: Vec<_> = (0..8).map(|x| binary_to_gray(x, 3)).collect();
+    assert_eq!(codes, [0, 1, 3, 2, 6, 7, 5, 4]);
+    const { assert!(gray_to_binary(binary_to_gray(usize::MAX, 64), 64) == usize::MAX) };
+}
@@ -0,0 +1,90 @@
+use crate::{
+    B0, B1, Bitstring, Dec, Shr, Tape,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Interleaves the lowest `Width` bits of `X` and `Y` into a single bitstring of (up to)
+/// `2 * Width` bits, with the bits of `X` in the even positions and those of `Y` in the odd
+/// positions. This is the Morton code (or Z-order curve index) of the coordinates `(X, Y)`.
+pub type Interleave<X /*: Bytes*/, Y /*: Bytes*/, Width /*: Bytes*/> =
+    <X as Interleaving>::Interleaved<Y, Width>;
+/// Splits the given bitstring into a [`Pair`](crate::Pair) of its even and odd bits, taking
+/// `Width` bits for each. This is the inverse of [`Interleave`], so it decodes a Morton code back
+/// into its coordinates `(X, Y)`.
+pub type Deinterleave<Z /*: Bytes*/, Width /*: Bytes*/> = (
+    <Z as Interleaving>::EvenBits<Width>,
+    <Shr<Z, B1> as Interleaving>::EvenBits<Width>,
+);
+
+/// A trait for interleaving bitstrings. This is implemented for all bitstrings.
+pub trait Interleaving: Bitstring {
+    /// The lowest `Width` bits of this bitstring interleaved with those of `Y`.
+    type Interleaved<Y: Bitstring, Width: Bitstring>: Bitstring;
+    /// The even-indexed bits of this bitstring, up to `Width` of them.
+    type EvenBits<Width: Bitstring>: Bitstring;
+}
+impl<X: Bitstring> Interleaving for X {
+    // Once we've taken enough bits, the rest are all zero
+    type Interleaved<Y: Bitstring, Width: Bitstring> =
+        IfB0<Width::Trimmed, Thunk<B0>, InterleaveRecurse<X, Y, Width>>;
+    type EvenBits<Width: Bitstring> = IfB0<Width::Trimmed, Thunk<B0>, EvenBitsRecurse<X, Width>>;
+}
+
+/// An internal recursion type for interleaving bitstrings. You shouldn't need to interact with
+/// this as an end user.
+pub struct InterleaveRecurse<X: Bitstring, Y: Bitstring, Width: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(X, Y, Width)>,
+}
+impl<X: Bitstring, Y: Bitstring, Width: Bitstring> Lazy for InterleaveRecurse<X, Y, Width> {
+    // Interleave the rest, then append the lowest bit of `Y` followed by the lowest bit of `X`
+    type Output = <Tape<
+        Tape<<X::Head as Interleaving>::Interleaved<Y::Head, Dec<Width>>, Y::Lsb>,
+        X::Lsb,
+    > as Bitstring>::Trimmed;
+}
+
+/// An internal recursion type for extracting the even bits of a bitstring. You shouldn't need to
+/// interact with this as an end user.
+pub struct EvenBitsRecurse<Z: Bitstring, Width: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(Z, Width)>,
+}
+impl<Z: Bitstring, Width: Bitstring> Lazy for EvenBitsRecurse<Z, Width> {
+    // Skip the odd bit above this one, and keep going
+    type Output = <Tape<
+        <<Z::Head as Bitstring>::Head as Interleaving>::EvenBits<Dec<Width>>,
+        Z::Lsb,
+    > as Bitstring>::Trimmed;
+}
+
+#[test]
+fn interleave() {
+    use crate::{First, Second, bs};
+
+    type W2 = bs!(1, 0);
+    type W4 = bs!(1, 0, 0);
+
+    // (3, 2) is 1101
+    assert_eq!(Interleave::<bs!(1, 1), bs!(1, 0), W2>::render(), "1101");
+    // (2, 3) is 1110
+    assert_eq!(Interleave::<bs!(1, 0), bs!(1, 1), W2>::render(), "1110");
+    // (5, 9) is 10010011
+    assert_eq!(
+        Interleave::<bs!(1, 0, 1), bs!(1, 0, 0, 1), W4>::render(),
+        "10010011"
+    );
+    assert_eq!(Interleave::<B0, B1, W4>::render(), "10");
+    assert_eq!(Interleave::<B1, B0, W4>::render(), "1");
+    // Bits past the width are ignored
+    assert_eq!(
+        Interleave::<bs!(1, 1, 1), bs!(1, 1, 1), W2>::render(),
+        "1111"
+    );
+    assert_eq!(Interleave::<bs!(1, 1), bs!(1, 1), B0>::render(), "0");
+
+    type Z = Interleave<bs!(1, 0, 1), bs!(1, 0, 0, 1), W4>;
+    assert_eq!(First::<Deinterleave<Z, W4>>::render(), "101");
+    assert_eq!(Second::<Deinterleave<Z, W4>>::render(), "1001");
+    assert_eq!(First::<Deinterleave<bs!(1, 1, 0, 1), W2>>::render(), "11");
+    assert_eq!(Second::<Deinterleave<bs!(1, 1, 0, 1), W2>>::render(), "10");
+}
@@ -1,5 +1,39 @@
 mod addition;
+mod comparison;
+mod count;
+mod digits;
+mod division;
+mod field;
+mod gray;
+mod interleave;
+mod multiplication;
+mod pow2;
+mod power;
+mod reverse;
+mod root;
+mod sequence;
+mod shift;
+mod signed;
 mod subtraction;
+mod wrapping;
 
-pub use addition::{Add, Sum};
-pub use subtraction::{Diff, Subtract};
+pub use addition::{Add, AddWithCarryIn, Avg, CarryOut, CarrySave, Sum};
+pub use comparison::{Compare, Ge, Gt, Le, Lt, Max, Min, SameValue, values_equal};
+pub use count::{BitLength, Count, HammingWeightAtLeast, Msb, MsbIndex, PopCount, popcount};
+pub use digits::{DigitCount, Digits, decimal_digits};
+pub use division::{CeilDiv, Divide, IsMultipleOf, Quotient, Remainder, is_multiple_of};
+pub use field::{BitField, Extract, GetBit, Insert, Truncate};
+pub use gray::{
+    BinaryToGray, GrayCode, GrayDecode, GrayEncode, GrayToBinary, binary_to_gray, gray_to_binary,
+};
+pub use interleave::{Deinterleave, Interleave, Interleaving};
+pub use multiplication::{Multiply, Product};
+pub use pow2::{DivPow2, IsPowerOfTwo, MulPow2, NextPow2, PrevPow2, Weight, bit_weight};
+pub use power::{Pow, Power};
+pub use reverse::{ByteReverse, byte_reverse};
+pub use root::{IRoot, Isqrt, Root};
+pub use sequence::{Fact, Fib, Sequence};
+pub use shift::{Shift, Shl, Shr};
+pub use signed::{Abs, Neg, SignExtend};
+pub use subtraction::{Dec, Diff, SubChecked, Subtract};
+pub use wrapping::{WrappingAdd, WrappingSub};
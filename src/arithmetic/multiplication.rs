@@ -0,0 +1,43 @@
+use crate::{
+    B0, Bitstring, Sum, Tape,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the product of the two given bitstrings.
+pub type Product<A /*: Bytes*/, B /*: Bytes*/> = <A as Multiply>::Product<B>;
+
+/// A trait for bitstrings that can be multiplied by other bitstrings. This is implemented for all
+/// bitstrings, using simple shift-and-add multiplication over the bits of the right-hand side.
+pub trait Multiply: Bitstring {
+    /// The product of this bitstring with the given one.
+    type Product<Rhs: Bitstring>: Bitstring;
+}
+impl<B: Bitstring> Multiply for B {
+    // Anything multiplied by zero is zero, which is also our base case
+    type Product<Rhs: Bitstring> = IfB0<Rhs::Trimmed, Thunk<B0>, MulRecurse<Self, Rhs>>;
+}
+
+/// An internal recursion type for multiplying two bitstrings. You shouldn't need to interact with
+/// this as an end user.
+pub struct MulRecurse<A: Bitstring, B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(A, B)>,
+}
+impl<A: Bitstring, B: Bitstring> Lazy for MulRecurse<A, B> {
+    // `A * B = 2 * (A * B::Head) + A * B::Lsb`, where the last term is either `A` or zero
+    type Output =
+        Sum<Tape<<A as Multiply>::Product<B::Head>, B0>, IfB0<B::Lsb, Thunk<B0>, Thunk<A>>>;
+}
+
+#[test]
+fn multiply() {
+    use crate::{B1, bs};
+
+    assert_eq!(Product::<bs!(1, 1), bs!(1, 0, 1)>::render(), "1111");
+    assert_eq!(Product::<bs!(1, 0, 1), bs!(1, 1)>::render(), "1111");
+    assert_eq!(Product::<bs!(1, 1, 1), bs!(1, 1, 1)>::render(), "110001");
+    assert_eq!(Product::<bs!(1, 1, 0), B1>::render(), "110");
+    assert_eq!(Product::<bs!(1, 1, 0), B0>::render(), "0");
+    assert_eq!(Product::<B0, bs!(1, 1, 0)>::render(), "0");
+    assert_eq!(Product::<bs!(0, 1, 0), bs!(0, 1, 1)>::render(), "110");
+}
@@ -0,0 +1,132 @@
+use crate::{B0, B1, BitLength, Bitstring, Dec, Mux, PopCount, SameValue, Shl, Shr};
+
+/// Returns [`B1`] if the given bitstring is a power of two (i.e. it has exactly one bit set), and
+/// [`B0`](crate::B0) otherwise. Zero is not a power of two.
+pub type IsPowerOfTwo<B /*: Bytes*/> = SameValue<PopCount<B>, B1>;
+/// Returns the smallest power of two greater than or equal to the given bitstring. Like
+/// [`usize::next_power_of_two`], this gives one for zero.
+pub type NextPow2<B /*: Bytes*/> =
+    Mux<IsPowerOfTwo<B>, Shl<B1, BitLength<B>>, <B as Bitstring>::Trimmed>;
+/// Returns the largest power of two less than or equal to the given bitstring. There's no such
+/// power of two for zero, so this gives zero for zero.
+pub type PrevPow2<B /*: Bytes*/> = Mux<SameValue<B, B0>, Shl<B1, Dec<BitLength<B>>>, B0>;
+/// Returns the positional weight of the bit at index `Index` (counting from the least-significant
+/// bit), which is the power of two `1 << Index`. See also [`bit_weight`].
+pub type Weight<Index /*: Bytes*/> = Shl<B1, Index>;
+/// Returns the given bitstring multiplied by two to the power of `K`. This is just a left shift, so
+/// it's much cheaper than a general [`Product`](crate::Product) with [`Weight<K>`].
+pub type MulPow2<B /*: Bytes*/, K /*: Bytes*/> = Shl<B, K>;
+/// Returns the given bitstring divided by two to the power of `K`, rounding down. This is just a
+/// right shift, so it's much cheaper than a general [`Quotient`](crate::Quotient) by
+/// [`Weight<K>`].
+pub type DivPow2<B /*: Bytes*/, K /*: Bytes*/> = Shr<B, K>;
+
+/// Returns the positional weight of the bit at the given index (counting from the least-significant
+/// bit), which is `1 << position`. This is a `const` mirror of [`Weight`].
+///
+/// # Panics
+///
+/// This panics if `position` is 128 or more, as the weight wouldn't fit in a `u128`.
+pub const fn bit_weight(position: usize) -> u128 {
+    assert!(position < 128, "bit position out of range for `u128`");
+    1 << position
+}
+
+#[test]
+fn next_pow2() {
+    use crate::{Bit, bs};
+
+    assert_eq!(<IsPowerOfTwo<bs!(1, 0, 0)> as Bit>::RENDER, "1");
+    assert_eq!(<IsPowerOfTwo<bs!(0, 1)> as Bit>::RENDER, "1");
+    assert_eq!(<IsPowerOfTwo<bs!(1, 1, 0)> as Bit>::RENDER, "0");
+    assert_eq!(<IsPowerOfTwo<B0> as Bit>::RENDER, "0");
+
+    // Powers of two are left as-is
+    assert_eq!(NextPow2::<bs!(1, 0, 0, 0)>::render(), "1000");
+    assert_eq!(NextPow2::<bs!(0, 0, 1, 0)>::render(), "10");
+    // Everything else rounds up
+    assert_eq!(NextPow2::<bs!(1, 0, 1)>::render(), "1000");
+    assert_eq!(NextPow2::<bs!(1, 1, 1)>::render(), "1000");
+    assert_eq!(NextPow2::<bs!(1, 0, 0, 1)>::render(), "10000");
+    assert_eq!(NextPow2::<bs!(1, 1)>::render(), "100");
+    // Zero and one both give one
+    assert_eq!(NextPow2::<B0>::render(), "1");
+    assert_eq!(NextPow2::<B1>::render(), "1");
+}
+
+#[test]
+fn prev_pow2() {
+    use crate::bs;
+
+    // Powers of two are left as-is
+    assert_eq!(PrevPow2::<bs!(1, 0, 0)>::render(), "100");
+    assert_eq!(PrevPow2::<bs!(0, 1, 0, 0, 0)>::render(), "1000");
+    assert_eq!(PrevPow2::<B1>::render(), "1");
+    // Everything else rounds down
+    assert_eq!(PrevPow2::<bs!(1, 0, 1)>::render(), "100");
+    assert_eq!(PrevPow2::<bs!(1, 1, 1)>::render(), "100");
+    assert_eq!(PrevPow2::<bs!(1, 1)>::render(), "10");
+    assert_eq!(PrevPow2::<bs!(1, 1, 1, 1, 1)>::render(), "10000");
+    // Zero has no power of two below it
+    assert_eq!(PrevPow2::<B0>::render(), "0");
+    assert_eq!(PrevPow2::<bs!(0, 0)>::render(), "0");
+}
+
+#[test]
+fn weight() {
+    use crate::bs;
+
+    assert_eq!(Weight::<B0>::render(), "1");
+    assert_eq!(Weight::<B1>::render(), "10");
+    assert_eq!(Weight::<bs!(1, 1)>::render(), "1000");
+    assert_eq!(Weight::<bs!(1, 0, 1)>::render(), "100000");
+    assert_eq!(Weight::<bs!(0, 1, 1)>::UNSIGNED, 8);
+
+    assert_eq!(bit_weight(0), 1);
+    assert_eq!(bit_weight(3), 8);
+    assert_eq!(bit_weight(64), 1 << 64);
+    assert_eq!(bit_weight(127), 1 << 127);
+    const { assert!(bit_weight(5) == Weight::<bs!(1, 0, 1)>::UNSIGNED as u128) };
+}
+
+#[test]
+fn mul_div_pow2() {
+    use crate::{Product, Quotient, bs};
+
+    // These should always match the general algorithms
+    assert_eq!(
+        MulPow2::<bs!(1, 0, 1), B0>::render(),
+        Product::<bs!(1, 0, 1), Weight<B0>>::render_trimmed()
+    );
+    assert_eq!(
+        MulPow2::<bs!(1, 0, 1), bs!(1, 1)>::render(),
+        Product::<bs!(1, 0, 1), Weight<bs!(1, 1)>>::render_trimmed()
+    );
+    assert_eq!(
+        MulPow2::<bs!(0, 1, 1), bs!(1, 0, 0)>::render(),
+        Product::<bs!(0, 1, 1), Weight<bs!(1, 0, 0)>>::render_trimmed()
+    );
+    assert_eq!(
+        DivPow2::<bs!(1, 0, 1), B1>::render_trimmed(),
+        Quotient::<bs!(1, 0, 1), Weight<B1>>::render_trimmed()
+    );
+    assert_eq!(
+        DivPow2::<bs!(1, 1, 0, 1, 1), bs!(1, 0)>::render_trimmed(),
+        Quotient::<bs!(1, 1, 0, 1, 1), Weight<bs!(1, 0)>>::render_trimmed()
+    );
+    assert_eq!(
+        DivPow2::<B1, bs!(1, 0, 1)>::render_trimmed(),
+        Quotient::<B1, Weight<bs!(1, 0, 1)>>::render_trimmed()
+    );
+
+    assert_eq!(MulPow2::<bs!(1, 0, 1), B1>::render(), "1010");
+    assert_eq!(MulPow2::<B0, bs!(1, 1)>::render_trimmed(), "0");
+    assert_eq!(DivPow2::<bs!(1, 0, 1), bs!(1, 1)>::render_trimmed(), "0");
+    assert_eq!(DivPow2::<bs!(1, 1, 0, 1, 1), B0>::render_trimmed(), "11011");
+
+    assert_eq!(MulPow2::<bs!(1, 1), bs!(1, 0)>::render(), "1100");
+    assert_eq!(
+        DivPow2::<bs!(1, 1, 0, 1), bs!(1, 0)>::render_trimmed(),
+        "11"
+    );
+}
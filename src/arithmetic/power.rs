@@ -0,0 +1,44 @@
+use crate::{
+    B1, Bitstring, Product,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns `B` raised to the power of `E`. Following convention, `0^0 = 1`.
+pub type Pow<B /*: Bytes*/, E /*: Bytes*/> = <B as Power>::Pow<E>;
+
+/// A trait for bitstrings that can be raised to the power of other bitstrings. This is implemented
+/// for all bitstrings, using square-and-multiply over the bits of the exponent.
+pub trait Power: Bitstring {
+    /// This bitstring raised to the power of the given one.
+    type Pow<E: Bitstring>: Bitstring;
+}
+impl<B: Bitstring> Power for B {
+    // Anything to the power of zero is one, which is also our base case
+    type Pow<E: Bitstring> = IfB0<E::Trimmed, Thunk<B1>, PowRecurse<Self, E>>;
+}
+
+/// An internal recursion type for exponentiation. You shouldn't need to interact with this as an
+/// end user.
+pub struct PowRecurse<B: Bitstring, E: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(B, E)>,
+}
+impl<B: Bitstring, E: Bitstring> Lazy for PowRecurse<B, E> {
+    // `B^E = (B^E::Head)^2 * B^E::Lsb`, where the last term is either `B` or one
+    type Output = Product<
+        Product<<B as Power>::Pow<E::Head>, <B as Power>::Pow<E::Head>>,
+        IfB0<E::Lsb, Thunk<B1>, Thunk<B>>,
+    >;
+}
+
+#[test]
+fn power() {
+    use crate::{B0, bs};
+
+    assert_eq!(Pow::<bs!(1, 1), bs!(1, 1)>::render(), "11011");
+    assert_eq!(Pow::<bs!(1, 0), bs!(1, 0, 1)>::render(), "100000");
+    assert_eq!(Pow::<bs!(1, 0, 1), B1>::render(), "101");
+    assert_eq!(Pow::<bs!(1, 0, 1), B0>::render(), "1");
+    assert_eq!(Pow::<B0, B0>::render(), "1");
+    assert_eq!(Pow::<B0, bs!(1, 1)>::render(), "0");
+}
@@ -0,0 +1,68 @@
+use crate::{B0, B1, Bitstring, GetBit, Tape};
+
+/// Returns the lowest 8 bits of the given bitstring in reverse order, so the least-significant bit
+/// becomes the most-significant bit of the byte, and so on. Any bits past the first byte are
+/// discarded, and missing bits are treated as zero, so this matches [`u8::reverse_bits`] (see
+/// also [`byte_reverse`]). The result is trimmed.
+pub type ByteReverse<B /*: Bytes*/> = <Tape<
+    Tape<
+        Tape<
+            Tape<
+                Tape<
+                    Tape<Tape<GetBit<B, B0>, GetBit<B, B1>>, GetBit<B, Tape<B1, B0>>>,
+                    GetBit<B, Tape<B1, B1>>,
+                >,
+                GetBit<B, Tape<Tape<B1, B0>, B0>>,
+            >,
+            GetBit<B, Tape<Tape<B1, B0>, B1>>,
+        >,
+        GetBit<B, Tape<Tape<B1, B1>, B0>>,
+    >,
+    GetBit<B, Tape<Tape<B1, B1>, B1>>,
+> as Bitstring>::Trimmed;
+
+/// Reverses the bits of the given byte. This is a `const` mirror of [`ByteReverse`], gathering
+/// each bit in the same way, so it should always agree with [`u8::reverse_bits`].
+pub const fn byte_reverse(x: u8) -> u8 {
+    let mut reversed = 0;
+    let mut i = 0;
+    // Bit `i` of the input becomes bit `7 - i` of the output
+    while i < 8 {
+        reversed |= ((x >> i) & 1) << (7 - i);
+        i += 1;
+    }
+    reversed
+}
+
+#[test]
+fn byte_reverse_matches() {
+    use crate::bs;
+
+    assert_eq!(ByteReverse::<B0>::render(), "0");
+    assert_eq!(ByteReverse::<B1>::render(), "10000000");
+    assert_eq!(ByteReverse::<bs!(1, 1, 0)>::render(), "1100000");
+    assert_eq!(
+        ByteReverse::<bs!(1, 0, 1, 1, 0, 0, 0, 1)>::render(),
+        "10001101"
+    );
+    assert_eq!(
+        ByteReverse::<bs!(1, 1, 1, 1, 1, 1, 1, 1)>::render(),
+        "11111111"
+    );
+    assert_eq!(ByteReverse::<bs!(0, 0, 0, 1, 0, 0, 0, 0)>::render(), "1000");
+    // Bits past the first byte are ignored
+    assert_eq!(
+        ByteReverse::<bs!(1, 0, 0, 0, 0, 0, 0, 1, 0)>::render(),
+        "1000000"
+    );
+
+    assert_eq!(byte_reverse(0), 0);
+    assert_eq!(byte_reverse(1), 0b1000_0000);
+    assert_eq!(byte_reverse(6), 0b0110_0000);
+    assert_eq!(byte_reverse(0b1011_0001), 0b1000_1101);
+    assert_eq!(byte_reverse(0b0001_0000), 0b0000_1000);
+    const { assert!(byte_reverse(0b1111_1111) == 0b1111_1111) };
+    for byte in 0..=u8::MAX {
+        assert_eq!(byte_reverse(byte), byte.reverse_bits());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    B0, B1, Bitstring, Le, Pow, Shr, Tape,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the integer `N`th root of `V`, i.e. the largest bitstring `R` such that `R^N <= V`.
+///
+/// As there's no meaningful zeroth root, `IRoot<V, B0>` is defined to be zero (rather than
+/// recursing forever).
+pub type IRoot<V /*: Bytes*/, N /*: Bytes*/> = <V as Root>::IRoot<N>;
+/// Returns the integer square root of `V`, i.e. the largest bitstring `R` such that `R^2 <= V`.
+pub type Isqrt<V /*: Bytes*/> = IRoot<V, Tape<B1, B0>>;
+
+/// A trait for bitstrings whose integer roots can be taken. This is implemented for all
+/// bitstrings.
+///
+/// This works by noting that the `N`th root of `V` is either twice the `N`th root of `V >> N`, or
+/// one more than that. Each step of the recursion therefore works out one bit of the root, taking
+/// `N` bits off `V`.
+pub trait Root: Bitstring {
+    /// The integer `N`th root of this bitstring.
+    type IRoot<N: Bitstring>: Bitstring;
+}
+impl<V: Bitstring> Root for V {
+    type IRoot<N: Bitstring> = IfB0<N::Trimmed, Thunk<B0>, RootRecurse<Self, N>>;
+}
+
+/// An internal recursion type for taking integer roots. You shouldn't need to interact with this
+/// as an end user.
+pub struct RootRecurse<V: Bitstring, N: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(V, N)>,
+}
+impl<V: Bitstring, N: Bitstring> Lazy for RootRecurse<V, N> {
+    // The root of zero is zero, which is also our base case
+    type Output = IfB0<V::Trimmed, Thunk<B0>, RootStep<V, N>>;
+}
+
+/// An internal recursion type for taking integer roots (a single step). You shouldn't need to
+/// interact with this as an end user.
+pub struct RootStep<V: Bitstring, N: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(V, N)>,
+}
+impl<V: Bitstring, N: Bitstring> Lazy for RootStep<V, N> {
+    // If `(2R + 1)^N <= V`, where `R` is the root of `V >> N`, then `2R + 1` is the root,
+    // otherwise it's `2R`
+    type Output = IfB0<
+        Le<Pow<Tape<IRoot<Shr<V, N>, N>, B1>, N>, V>,
+        Thunk<<Tape<IRoot<Shr<V, N>, N>, B0> as Bitstring>::Trimmed>,
+        Thunk<<Tape<IRoot<Shr<V, N>, N>, B1> as Bitstring>::Trimmed>,
+    >;
+}
+
+#[test]
+fn root() {
+    use crate::bs;
+
+    type Three = bs!(1, 1);
+
+    // Perfect powers
+    assert_eq!(IRoot::<bs!(1, 1, 0, 1, 1), Three>::render(), "11");
+    assert_eq!(IRoot::<bs!(1, 0, 0, 0, 0, 0, 0), Three>::render(), "100");
+    assert_eq!(Isqrt::<bs!(1, 0, 0, 0, 0)>::render(), "100");
+    assert_eq!(Isqrt::<bs!(1, 1, 0, 0, 0, 1)>::render(), "111");
+    // Near misses floor
+    assert_eq!(IRoot::<bs!(1, 1, 0, 1, 0), Three>::render(), "10");
+    assert_eq!(IRoot::<bs!(1, 1, 1, 0, 0), Three>::render(), "11");
+    assert_eq!(Isqrt::<bs!(1, 1, 1, 1)>::render(), "11");
+    assert_eq!(Isqrt::<bs!(1, 0)>::render(), "1");
+    // Identity
+    assert_eq!(IRoot::<bs!(1, 0, 1, 1, 0), B1>::render(), "10110");
+    // Zero
+    assert_eq!(Isqrt::<B0>::render(), "0");
+    assert_eq!(IRoot::<bs!(1, 0, 1), B0>::render(), "0");
+}
@@ -0,0 +1,84 @@
+use crate::{
+    B0, B1, Bitstring, Dec, Product, Sum,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the `N`th Fibonacci number, where `Fib<B0>` is zero and `Fib<B1>` is one. This recurses
+/// once for each number up to `N`, so very large indices will hit the recursion limit.
+pub type Fib<N /*: Bytes*/> = <N as Sequence>::Fib;
+/// Returns the factorial of `N`, where `Fact<B0>` is one. Like [`Fib`], this recurses once for each
+/// number up to `N`.
+pub type Fact<N /*: Bytes*/> = <N as Sequence>::Fact;
+
+/// A trait for computing well-known integer sequences, indexed by bitstrings. This is implemented
+/// for all bitstrings.
+pub trait Sequence: Bitstring {
+    /// The Fibonacci number at this index.
+    type Fib: Bitstring;
+    /// The Fibonacci number after the one at this index, which is kept alongside [`Self::Fib`] so
+    /// we only need to recurse linearly.
+    type FibNext: Bitstring;
+    /// The factorial of this bitstring.
+    type Fact: Bitstring;
+}
+impl<N: Bitstring> Sequence for N {
+    type Fib = IfB0<N::Trimmed, Thunk<B0>, FibRecurse<N>>;
+    type FibNext = IfB0<N::Trimmed, Thunk<B1>, FibNextRecurse<N>>;
+    type Fact = IfB0<N::Trimmed, Thunk<B1>, FactRecurse<N>>;
+}
+
+/// An internal recursion type for Fibonacci numbers. You shouldn't need to interact with this as
+/// an end user.
+pub struct FibRecurse<N: Bitstring> {
+    _phantom: ::std::marker::PhantomData<N>,
+}
+impl<N: Bitstring> Lazy for FibRecurse<N> {
+    // `F(N)` is just the next number after `F(N - 1)`
+    type Output = <Dec<N> as Sequence>::FibNext;
+}
+
+/// An internal recursion type for Fibonacci numbers. You shouldn't need to interact with this as
+/// an end user.
+pub struct FibNextRecurse<N: Bitstring> {
+    _phantom: ::std::marker::PhantomData<N>,
+}
+impl<N: Bitstring> Lazy for FibNextRecurse<N> {
+    // `F(N + 1) = F(N - 1) + F(N)`
+    type Output = Sum<<Dec<N> as Sequence>::Fib, <Dec<N> as Sequence>::FibNext>;
+}
+
+/// An internal recursion type for factorials. You shouldn't need to interact with this as an end
+/// user.
+pub struct FactRecurse<N: Bitstring> {
+    _phantom: ::std::marker::PhantomData<N>,
+}
+impl<N: Bitstring> Lazy for FactRecurse<N> {
+    type Output = Product<N, <Dec<N> as Sequence>::Fact>;
+}
+
+#[test]
+fn fibonacci() {
+    use crate::bs;
+
+    assert_eq!(<Fib<B0> as Bitstring>::UNSIGNED, 0);
+    assert_eq!(<Fib<B1> as Bitstring>::UNSIGNED, 1);
+    assert_eq!(<Fib<bs!(1, 0)> as Bitstring>::UNSIGNED, 1);
+    assert_eq!(<Fib<bs!(1, 1)> as Bitstring>::UNSIGNED, 2);
+    assert_eq!(<Fib<bs!(1, 0, 1)> as Bitstring>::UNSIGNED, 5);
+    assert_eq!(<Fib<bs!(1, 0, 1, 0)> as Bitstring>::UNSIGNED, 55);
+    assert_eq!(<Fib<bs!(0, 1, 1, 0)> as Bitstring>::UNSIGNED, 8);
+    assert_eq!(Fib::<bs!(1, 0, 0, 0, 0)>::render(), "1111011011");
+}
+
+#[test]
+fn factorial() {
+    use crate::bs;
+
+    assert_eq!(<Fact<B0> as Bitstring>::UNSIGNED, 1);
+    assert_eq!(<Fact<B1> as Bitstring>::UNSIGNED, 1);
+    assert_eq!(<Fact<bs!(1, 0)> as Bitstring>::UNSIGNED, 2);
+    assert_eq!(<Fact<bs!(1, 1)> as Bitstring>::UNSIGNED, 6);
+    assert_eq!(Fact::<bs!(1, 0, 1)>::render(), "1111000");
+    assert_eq!(<Fact<bs!(1, 1, 1)> as Bitstring>::UNSIGNED, 5040);
+}
@@ -0,0 +1,103 @@
+use crate::{
+    B0, B1, Bit, Bitstring, Diff, Tape,
+    bits::IfB0,
+    conditionals::bitstring::{Lazy, Thunk},
+};
+
+/// Returns the given bitstring shifted left by `K` bits, where `K` is itself a bitstring. This is
+/// equivalent to multiplying by `2^K`.
+pub type Shl<B /*: Bytes*/, K /*: Bytes*/> = <B as Shift>::Shl<K>;
+/// Returns the given bitstring shifted right by `K` bits, where `K` is itself a bitstring. This is
+/// equivalent to dividing by `2^K` and discarding the remainder.
+pub type Shr<B /*: Bytes*/, K /*: Bytes*/> = <B as Shift>::Shr<K>;
+
+/// A trait for bitstrings that can be shifted by other bitstrings. This is implemented for all
+/// bitstrings. Both shifts produce trimmed outputs.
+pub trait Shift: Bitstring {
+    /// This bitstring shifted left by the given number of bits.
+    type Shl<K: Bitstring>: Bitstring;
+    /// This bitstring shifted right by the given number of bits.
+    type Shr<K: Bitstring>: Bitstring;
+}
+impl<B: Bitstring> Shift for B {
+    // If there's nothing left to shift by, we're done, otherwise shift by one and recurse with one
+    // less to shift by
+    type Shl<K: Bitstring> = IfB0<K::Trimmed, Thunk<B::Trimmed>, ShlRecurse<B, K>>;
+    type Shr<K: Bitstring> = IfB0<K::Trimmed, Thunk<B::Trimmed>, ShrRecurse<B, K>>;
+}
+
+/// An internal recursion type for shifting a bitstring left. You shouldn't need to interact with
+/// this as an end user.
+pub struct ShlRecurse<B: Bitstring, K: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(B, K)>,
+}
+impl<B: Bitstring, K: Bitstring> Lazy for ShlRecurse<B, K> {
+    // Shifting left by one is just appending a zero
+    type Output = <Tape<B, B0> as Shift>::Shl<Diff<K, B1>>;
+}
+
+/// An internal recursion type for shifting a bitstring right. You shouldn't need to interact with
+/// this as an end user.
+pub struct ShrRecurse<B: Bitstring, K: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(B, K)>,
+}
+impl<B: Bitstring, K: Bitstring> Lazy for ShrRecurse<B, K> {
+    // Shifting right by one is just dropping the least-significant bit
+    type Output = <B::Head as Shift>::Shr<Diff<K, B1>>;
+}
+
+// Bitstrings are zero-sized, so we can wire the shift operators up on instances too, producing the
+// instance of whatever the shifted type is
+macro_rules! impl_shift_ops {
+    ($([$($generics:tt)*] $ty:ty),+) => {
+        $(
+            impl<$($generics)* Rhs: Bitstring> ::core::ops::Shl<Rhs> for $ty {
+                type Output = Shl<Self, Rhs>;
+
+                fn shl(self, _rhs: Rhs) -> Self::Output {
+                    Default::default()
+                }
+            }
+            impl<$($generics)* Rhs: Bitstring> ::core::ops::Shr<Rhs> for $ty {
+                type Output = Shr<Self, Rhs>;
+
+                fn shr(self, _rhs: Rhs) -> Self::Output {
+                    Default::default()
+                }
+            }
+        )+
+    };
+}
+impl_shift_ops!([] B0, [] B1, [H: Bitstring, L: Bit,] Tape<H, L>);
+
+#[test]
+fn shift() {
+    use crate::bs;
+
+    assert_eq!(Shl::<bs!(1, 0, 1), bs!(1, 0)>::render(), "10100");
+    assert_eq!(Shl::<bs!(1, 0, 1), B0>::render(), "101");
+    assert_eq!(Shl::<B0, bs!(1, 1)>::render(), "0");
+    assert_eq!(Shl::<B1, bs!(0, 1)>::render(), "10");
+    assert_eq!(Shr::<bs!(1, 0, 1), bs!(1, 0)>::render(), "1");
+    assert_eq!(Shr::<bs!(1, 1, 0, 1), B1>::render(), "110");
+    assert_eq!(Shr::<bs!(1, 0, 1), bs!(1, 0, 0)>::render(), "0");
+    assert_eq!(Shr::<bs!(0, 1, 1), B0>::render(), "11");
+}
+
+#[test]
+fn shift_ops() {
+    use crate::bs;
+
+    fn render_of<B: Bitstring>(_: B) -> String {
+        B::render()
+    }
+
+    let shifted: Shl<bs!(1, 0, 1), bs!(1, 0)> = <bs!(1, 0, 1)>::default() << <bs!(1, 0)>::default();
+    assert_eq!(render_of(shifted), "10100");
+    assert_eq!(render_of(B1 << B1), "10");
+    assert_eq!(render_of(<bs!(1, 1, 0)>::default() >> B1), "11");
+    assert_eq!(
+        render_of(<bs!(1, 1, 0)>::default() >> <bs!(1, 1)>::default()),
+        "0"
+    );
+}
@@ -0,0 +1,73 @@
+use crate::{B0, B1, Dec, Diff, GetBit, Mux, Or, Shl, Truncate, WrappingSub};
+
+/// Returns the two's-complement negation of the given bitstring, treated as a signed value of
+/// `Width` bits. The result is the `Width`-bit pattern of the negated value (trimmed), so
+/// negating the most negative value gives back the same value, just as with
+/// [`i8::wrapping_neg`].
+pub type Neg<B /*: Bytes*/, Width /*: Bytes*/> = WrappingSub<B0, B, Width>;
+/// Returns the absolute value of the given bitstring, treated as a signed two's-complement value
+/// of `Width` bits (so the bit at index `Width - 1` is the sign bit). The result is an *unsigned*
+/// bitstring, so the absolute value of the most negative value (e.g. `1000` in four bits, which
+/// is -8) is correctly given as `1000` (8), even though that can't be represented as a positive
+/// signed value of the same width. `Width` must not be zero.
+pub type Abs<B /*: Bytes*/, Width /*: Bytes*/> =
+    Mux<GetBit<B, Dec<Width>>, Truncate<B, Width>, Neg<B, Width>>;
+/// Widens the given bitstring, treated as a signed two's-complement value of `FromWidth` bits, to
+/// `ToWidth` bits, by copying its sign bit into all the new bits. Non-negative values are just
+/// truncated to `FromWidth` bits, and negative ones have the bits from `FromWidth` up to
+/// `ToWidth` set. `FromWidth` must not be zero, and mustn't be greater than `ToWidth`.
+pub type SignExtend<B /*: Bytes*/, FromWidth /*: Bytes*/, ToWidth /*: Bytes*/> = Mux<
+    GetBit<B, Dec<FromWidth>>,
+    Truncate<B, FromWidth>,
+    Or<Truncate<B, FromWidth>, Diff<Shl<B1, ToWidth>, Shl<B1, FromWidth>>>,
+>;
+
+#[test]
+fn signed() {
+    use crate::{Bitstring, bs};
+
+    type W4 = bs!(1, 0, 0);
+
+    assert_eq!(Neg::<bs!(1, 0, 1), W4>::render(), "1011");
+    assert_eq!(Neg::<bs!(1, 0, 1, 1), W4>::render(), "101");
+    assert_eq!(Neg::<B0, W4>::render(), "0");
+    assert_eq!(Neg::<bs!(1, 0, 0, 0), W4>::render(), "1000");
+
+    // Positive
+    assert_eq!(Abs::<bs!(1, 0, 1), W4>::render(), "101");
+    assert_eq!(Abs::<bs!(1, 1, 1), W4>::render(), "111");
+    // Negative
+    assert_eq!(Abs::<bs!(1, 0, 1, 1), W4>::render(), "101");
+    assert_eq!(Abs::<bs!(1, 1, 1, 1), W4>::render(), "1");
+    assert_eq!(Abs::<Neg<bs!(1, 1, 0), W4>, W4>::render(), "110");
+    // Zero
+    assert_eq!(Abs::<B0, W4>::render(), "0");
+    // The most negative value's absolute value only fits as an unsigned value
+    assert_eq!(Abs::<bs!(1, 0, 0, 0), W4>::render(), "1000");
+    // Single-bit values are either 0 or -1
+    assert_eq!(Abs::<B1, B1>::render(), "1");
+}
+
+#[test]
+fn sign_extend() {
+    use crate::{Bitstring, bs};
+
+    type W3 = bs!(1, 1);
+    type W6 = bs!(1, 1, 0);
+
+    // Negative
+    assert_eq!(SignExtend::<bs!(1, 1, 1), W3, W6>::render(), "111111");
+    assert_eq!(SignExtend::<bs!(1, 0, 0), W3, W6>::render(), "111100");
+    assert_eq!(SignExtend::<B1, B1, bs!(1, 0, 0)>::render(), "1111");
+    // Positive
+    assert_eq!(SignExtend::<bs!(0, 1, 1), W3, W6>::render(), "11");
+    assert_eq!(SignExtend::<B0, W3, W6>::render(), "0");
+    // Bits past the original width are ignored
+    assert_eq!(SignExtend::<bs!(1, 0, 0, 1, 1), W3, W6>::render(), "11");
+    // Extending to the same width changes nothing
+    assert_eq!(SignExtend::<bs!(1, 0, 1), W3, W3>::render(), "101");
+    assert_eq!(SignExtend::<bs!(0, 1, 0), W3, W3>::render(), "10");
+
+    // The extended value still negates properly in the new width
+    assert_eq!(Neg::<SignExtend<bs!(1, 1, 0), W3, W6>, W6>::render(), "10");
+}
@@ -1,5 +1,5 @@
 use crate::{
-    B0, Bit, BitAnd, BitNot, BitOr, BitXor, Bitstring, Or, Tape,
+    B0, B1, Bit, BitAnd, BitNot, BitOr, BitXor, Bitstring, Lt, Or, Tape,
     bits::IfB0,
     conditionals::bitstring::{Lazy, Thunk},
 };
@@ -7,6 +7,12 @@ use crate::{
 /// Returns the difference between the two given bitstrings. See [`Subtract`] for how this handles
 /// underflows.
 pub type Diff<A /*: Bytes*/, B /*: Bytes*/> = <A as Subtract>::Difference<B>;
+/// Returns the given bitstring minus one. See [`Subtract`] for how this handles zero.
+pub type Dec<N /*: Bytes*/> = Diff<N, B1>;
+/// Returns a [`Pair`](crate::Pair) of the difference between the two given bitstrings (as in
+/// [`Diff`]) and a [`Bit`] indicating whether or not the subtraction underflowed (i.e. whether or
+/// not `A < B`, which is the final borrow of the subtraction).
+pub type SubChecked<A /*: Bytes*/, B /*: Bytes*/> = (Diff<A, B>, Lt<A, B>);
 
 /// A trait for the subtraction of two bitstrings. This is implemented for all bitstrings for
 /// convenience, but will provide sane results only for the subtraction of a small bitstring from a
@@ -77,8 +83,6 @@ impl<B: Bit> HalfSubtract for B {
 
 #[test]
 fn subtract() {
-    use crate::B1;
-
     type T10 = Tape<B1, B0>;
     type T01 = Tape<B0, B1>;
     type T101 = Tape<Tape<B1, B0>, B1>;
@@ -88,8 +92,29 @@ fn subtract() {
     assert_eq!(Diff::<T10, T01>::render(), "1");
     assert_eq!(Diff::<T101, T01>::render(), "100");
     assert_eq!(Diff::<T110, T10>::render(), "100");
+    assert_eq!(Dec::<T110>::render(), "101");
+    assert_eq!(Dec::<T10>::render(), "1");
+    assert_eq!(Dec::<B1>::render(), "0");
 
     // Underflow
     assert_eq!(Diff::<T1011, T110>::render(), "101"); // 11 - 6 = 5
     assert_eq!(Diff::<T110, T1011>::render(), "1011"); // 6 - 11 = 11 (mod 16)
 }
+
+#[test]
+fn subtract_checked() {
+    use crate::{First, Second, bs};
+
+    type T6 = bs!(1, 1, 0);
+    type T11 = bs!(1, 0, 1, 1);
+
+    assert_eq!(<Second<SubChecked<T6, T11>> as Bit>::RENDER, "1");
+    assert_eq!(
+        First::<SubChecked<T6, T11>>::render(),
+        Diff::<T6, T11>::render()
+    );
+    assert_eq!(<Second<SubChecked<T11, T6>> as Bit>::RENDER, "0");
+    assert_eq!(First::<SubChecked<T11, T6>>::render(), "101");
+    assert_eq!(<Second<SubChecked<T6, T6>> as Bit>::RENDER, "0");
+    assert_eq!(First::<SubChecked<T6, T6>>::render(), "0");
+}
@@ -0,0 +1,41 @@
+use crate::{B1, Diff, Shl, Sum, Truncate};
+
+/// Returns the sum of the two given bitstrings, wrapped to `Width` bits (i.e. modulo
+/// `2^Width`). The result is trimmed, like [`Truncate`].
+pub type WrappingAdd<A /*: Bytes*/, B /*: Bytes*/, Width /*: Bytes*/> = Truncate<Sum<A, B>, Width>;
+/// Returns the difference of the two given bitstrings, wrapped to `Width` bits (i.e. modulo
+/// `2^Width`). Unlike [`Diff`], this has well-defined behaviour when `A < B`, because both
+/// operands are first truncated to `Width` bits, and `2^Width` is added to `A` so the subtraction
+/// can't underflow. The result is trimmed, like [`Truncate`].
+pub type WrappingSub<A /*: Bytes*/, B /*: Bytes*/, Width /*: Bytes*/> =
+    Truncate<Diff<Sum<Truncate<A, Width>, Shl<B1, Width>>, Truncate<B, Width>>, Width>;
+
+#[test]
+fn wrapping() {
+    use crate::{B0, Bitstring, bs};
+
+    type W4 = bs!(1, 0, 0);
+
+    assert_eq!(WrappingAdd::<bs!(1, 1, 1, 1), B1, W4>::render(), "0");
+    assert_eq!(
+        WrappingAdd::<bs!(1, 1, 1, 1), bs!(1, 1), W4>::render(),
+        "10"
+    );
+    assert_eq!(WrappingAdd::<bs!(1, 1, 1, 0), B1, W4>::render(), "1111");
+    assert_eq!(WrappingAdd::<bs!(1, 0), bs!(1, 1), W4>::render(), "101");
+    assert_eq!(WrappingAdd::<bs!(1, 1), bs!(1, 1), B0>::render(), "0");
+
+    assert_eq!(WrappingSub::<B0, B1, W4>::render(), "1111");
+    assert_eq!(
+        WrappingSub::<bs!(1, 1, 0), bs!(1, 0, 1, 1), W4>::render(),
+        "1011"
+    );
+    assert_eq!(WrappingSub::<bs!(1, 0, 1), bs!(1, 0, 1), W4>::render(), "0");
+    assert_eq!(WrappingSub::<bs!(1, 0, 1), bs!(1, 1), W4>::render(), "10");
+    // Operands wider than the width are truncated first
+    assert_eq!(WrappingSub::<bs!(1, 0, 0, 0, 0), B1, W4>::render(), "1111");
+    assert_eq!(
+        WrappingSub::<bs!(1, 1), bs!(1, 0, 0, 0, 1), W4>::render(),
+        "10"
+    );
+}
@@ -0,0 +1,423 @@
+//! Regression tests for the drop-safety of [`Array`]. Most of the array internals rely on unsafe
+//! reinterpretation of memory, so these make sure every element that gets created is dropped
+//! exactly once, including when a panic unwinds partway through construction. None of these rely
+//! on anything but the standard library, so they can be run under Miri as-is.
+
+use super::{Array, internal_depth};
+use crate::bs;
+use std::{
+    cell::{Cell, RefCell},
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+thread_local! {
+    /// The log of every [`DropCounter`] created on this thread. Each test runs on its own thread,
+    /// so tests can't interfere with each other's logs.
+    static LOG: RefCell<DropLog> = RefCell::new(DropLog::default());
+}
+
+#[derive(Default)]
+struct DropLog {
+    /// The number of counters created so far, which is used to give each one a unique ID.
+    created: usize,
+    /// The IDs of every counter that has been dropped, in order.
+    dropped: Vec<usize>,
+    /// If set, creating a new counter will panic once this many have been created.
+    panic_after: Option<usize>,
+}
+
+/// A type that records its creation and destruction in a thread-local log, so we can check that
+/// nothing is leaked or dropped twice. Dropping the same counter twice will panic.
+pub(super) struct DropCounter {
+    id: usize,
+}
+impl DropCounter {
+    pub(super) fn new() -> Self {
+        LOG.with_borrow_mut(|log| {
+            if log.panic_after.is_some_and(|limit| log.created >= limit) {
+                panic!("drop counter creation limit reached");
+            }
+
+            log.created += 1;
+            Self {
+                id: log.created - 1,
+            }
+        })
+    }
+
+    /// Resets the log for this thread.
+    pub(super) fn reset() {
+        LOG.set(DropLog::default());
+    }
+
+    /// Makes creating new counters panic once `limit` counters have been created in total.
+    pub(super) fn panic_after(limit: usize) {
+        LOG.with_borrow_mut(|log| log.panic_after = Some(limit));
+    }
+
+    /// Returns the number of counters that have been created, but not yet dropped.
+    pub(super) fn live() -> usize {
+        LOG.with_borrow(|log| log.created - log.dropped.len())
+    }
+
+    /// Asserts that every counter created has been dropped exactly once.
+    pub(super) fn assert_all_dropped() {
+        LOG.with_borrow(|log| {
+            let mut dropped = log.dropped.clone();
+            dropped.sort_unstable();
+            assert_eq!(dropped, (0..log.created).collect::<Vec<_>>());
+        });
+    }
+}
+impl Default for DropCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Clone for DropCounter {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        LOG.with_borrow_mut(|log| {
+            assert!(
+                !log.dropped.contains(&self.id),
+                "drop counter {} dropped twice",
+                self.id
+            );
+            log.dropped.push(self.id);
+        });
+    }
+}
+
+type A5 = Array<DropCounter, bs!(1, 0, 1)>;
+
+#[test]
+fn drop_new() {
+    DropCounter::reset();
+
+    let array = A5::new();
+    let boxed = A5::new_boxed();
+    assert_eq!(DropCounter::live(), 10);
+    drop(array);
+    drop(boxed);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_clone() {
+    DropCounter::reset();
+
+    let array = A5::new();
+    let cloned = array.clone();
+    assert_eq!(DropCounter::live(), 10);
+    drop(array);
+    assert_eq!(DropCounter::live(), 5);
+    drop(cloned);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_from_slice() {
+    DropCounter::reset();
+
+    let source: Vec<_> = (0..5).map(|_| DropCounter::new()).collect();
+    let array = A5::new_from_slice(&source);
+    let boxed = A5::new_boxed_from_slice(&source);
+    assert!(A5::try_new_from_slice(&source[..4]).is_err());
+    assert_eq!(DropCounter::live(), 15);
+    drop((source, array, boxed));
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_from_fn() {
+    DropCounter::reset();
+
+    let array = A5::from_fn(|_| DropCounter::new());
+    let boxed = A5::from_fn_boxed(|_| DropCounter::new());
+    assert_eq!(DropCounter::live(), 10);
+    drop((array, boxed));
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_try_from_fn() {
+    DropCounter::reset();
+
+    let array = A5::try_from_fn(|_| Ok::<_, ()>(DropCounter::new())).unwrap();
+    assert_eq!(DropCounter::live(), 5);
+    drop(array);
+
+    // Failing on the fourth element should drop the first three
+    let result = A5::try_from_fn(|i| {
+        if i == 3 {
+            Err(i)
+        } else {
+            Ok(DropCounter::new())
+        }
+    });
+    assert!(matches!(result, Err(3)));
+    assert_eq!(DropCounter::live(), 0);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_map_ref() {
+    DropCounter::reset();
+
+    let array = A5::new();
+    let mapped = array.map_ref(|_| DropCounter::new());
+    assert_eq!(DropCounter::live(), 10);
+    drop((array, mapped));
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_try_map_ref() {
+    DropCounter::reset();
+
+    let array = A5::new();
+    let mapped = array
+        .try_map_ref(|_| Ok::<_, ()>(DropCounter::new()))
+        .unwrap();
+    assert_eq!(DropCounter::live(), 10);
+    drop(mapped);
+
+    // Failing on the fourth element should drop the first three, but leave the source alone
+    let mut i = 0;
+    let result = array.try_map_ref(|_| {
+        i += 1;
+        if i == 4 {
+            Err(i)
+        } else {
+            Ok(DropCounter::new())
+        }
+    });
+    assert!(matches!(result, Err(4)));
+    assert_eq!(DropCounter::live(), 5);
+    drop(array);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_fill_from_iter() {
+    DropCounter::reset();
+
+    let mut array = A5::new();
+    // The three overwritten elements are dropped straight away
+    assert_eq!(array.fill_from_iter((0..3).map(|_| DropCounter::new())), 3);
+    assert_eq!(DropCounter::live(), 5);
+    drop(array);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_boxed_trait_objects() {
+    DropCounter::reset();
+
+    // Each closure owns a counter, so dropping the closures should drop the counters
+    let callbacks = Array::<Box<dyn Fn() -> usize>, bs!(1, 1)>::from_fn(|i| {
+        let counter = DropCounter::new();
+        // Borrowing the whole counter makes the closure capture all of it, not just its ID
+        Box::new(move || {
+            let counter = &counter;
+            counter.id + i
+        })
+    });
+    assert_eq!(DropCounter::live(), 3);
+    assert_eq!(callbacks[2](), 4);
+    let mut iter = callbacks.into_iter();
+    drop(iter.next());
+    assert_eq!(DropCounter::live(), 2);
+    drop(iter);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_const_generic_conversions() {
+    DropCounter::reset();
+
+    let array: [DropCounter; 5] = A5::new().try_into().unwrap();
+    let array = A5::try_from(array).unwrap();
+    assert_eq!(DropCounter::live(), 5);
+    // Failed conversions drop the source
+    assert!(<[DropCounter; 4]>::try_from(array).is_err());
+    assert_eq!(DropCounter::live(), 0);
+
+    let array = A5::from_array_iter(std::array::from_fn::<_, 5, _>(|_| DropCounter::new()));
+    assert_eq!(DropCounter::live(), 5);
+    drop(array);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_split_head() {
+    DropCounter::reset();
+
+    let (head, tail) = A5::new().split_head::<bs!(1, 0)>();
+    assert_eq!(DropCounter::live(), 5);
+    drop(head);
+    assert_eq!(DropCounter::live(), 3);
+    drop(tail);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_interleave() {
+    DropCounter::reset();
+
+    let array = Array::<DropCounter, bs!(1, 1, 0)>::new();
+    let (left, right) = array.deinterleave();
+    assert_eq!(DropCounter::live(), 6);
+    drop(left);
+    assert_eq!(DropCounter::live(), 3);
+    let interleaved = right.clone().interleave(right);
+    assert_eq!(DropCounter::live(), 6);
+    drop(interleaved);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_resize() {
+    DropCounter::reset();
+
+    let shrunk = A5::new().resize::<bs!(1, 1)>();
+    // The two excess elements should have been dropped immediately
+    assert_eq!(DropCounter::live(), 3);
+    let grown = shrunk.resize::<bs!(1, 0, 1)>();
+    assert_eq!(DropCounter::live(), 5);
+    drop(grown);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_into_boxed_slice() {
+    DropCounter::reset();
+
+    let slice = A5::new_boxed().into_boxed_slice();
+    assert_eq!(DropCounter::live(), 5);
+    drop(slice);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_into_iter() {
+    DropCounter::reset();
+
+    let mut iter = A5::new().into_iter_rev();
+    let last = iter.next().unwrap();
+    iter.next();
+    // Two elements have been yielded (and one of those dropped), and the rest are in the iterator
+    assert_eq!(DropCounter::live(), 4);
+    drop(iter);
+    assert_eq!(DropCounter::live(), 1);
+    drop(last);
+
+    // Consuming from both ends should leave the middle to be dropped
+    let mut iter = A5::new().into_iter();
+    iter.next();
+    iter.next_back();
+    assert_eq!(DropCounter::live(), 3);
+    drop(iter);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_swap_remove() {
+    DropCounter::reset();
+
+    let (elem, rest) = A5::new().swap_remove::<bs!(1, 0)>();
+    assert_eq!(DropCounter::live(), 5);
+    drop(elem);
+    assert_eq!(DropCounter::live(), 4);
+    drop(rest);
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_on_panic() {
+    // Each of these will panic when creating the fourth element
+    DropCounter::reset();
+    DropCounter::panic_after(3);
+    assert!(catch_unwind(A5::new).is_err());
+    DropCounter::assert_all_dropped();
+
+    DropCounter::reset();
+    DropCounter::panic_after(3);
+    assert!(catch_unwind(A5::new_boxed).is_err());
+    DropCounter::assert_all_dropped();
+
+    DropCounter::reset();
+    DropCounter::panic_after(3);
+    assert!(catch_unwind(|| A5::from_fn(|_| DropCounter::new())).is_err());
+    DropCounter::assert_all_dropped();
+
+    DropCounter::reset();
+    DropCounter::panic_after(3);
+    assert!(catch_unwind(|| A5::from_fn_boxed(|_| DropCounter::new())).is_err());
+    DropCounter::assert_all_dropped();
+
+    DropCounter::reset();
+    let array = A5::new();
+    DropCounter::panic_after(8);
+    assert!(catch_unwind(AssertUnwindSafe(|| array.clone())).is_err());
+    DropCounter::panic_after(11);
+    assert!(catch_unwind(AssertUnwindSafe(|| array.map_ref(|_| DropCounter::new()))).is_err());
+    // Only the partially-built arrays were dropped, not the original
+    assert_eq!(DropCounter::live(), 5);
+    drop(array);
+    DropCounter::assert_all_dropped();
+
+    // Resizing consumes the original, so everything should be dropped if filling the new slots
+    // panics
+    DropCounter::reset();
+    let array = A5::new();
+    DropCounter::panic_after(6);
+    assert!(catch_unwind(AssertUnwindSafe(|| array.resize::<bs!(1, 1, 1)>())).is_err());
+
+    DropCounter::assert_all_dropped();
+}
+
+#[test]
+fn drop_huge() {
+    thread_local! {
+        static DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// A cheaper version of [`DropCounter`] for when there are too many elements to check
+    /// individually.
+    #[derive(Default)]
+    struct Tally;
+    impl Drop for Tally {
+        fn drop(&mut self) {
+            DROPS.set(DROPS.get() + 1);
+        }
+    }
+
+    // All ones is the case with the most elements for a given depth
+    type Huge = bs!(1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1);
+    const _: () = assert!(internal_depth::<Huge>() == 20);
+
+    let array = Array::<Tally, Huge>::new_boxed();
+    drop(array);
+    assert_eq!(DROPS.get(), (1 << 20) - 1);
+}
@@ -0,0 +1,2386 @@
+use crate::{B1, Bitstring, Dec, Diff, Quotient, Shl, Shr, bits::IsB0, conditional_system};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{FusedIterator, Rev},
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Index, IndexMut, Range, RangeBounds},
+    ptr::NonNull,
+    slice::SliceIndex,
+};
+
+/// A stack-allocated array storing instances of `T`, whose length is defined by the [`Bitstring`]
+/// `B`. This is almost identical to `generic_array`, except it avoids any `where` clauses, and
+/// uses our internal bitstring type.
+///
+/// Like `generic_array`, this internally stores a binary-tree-like structure that gets cast to a
+/// slice, allowing it to operate identically to an array. We basically just build up the size of
+/// the type at compile-time based on the given bitstring.
+///
+/// # Internal representation
+///
+/// Internally, like `generic_array`, this stores the associated array type of the given bitstring,
+/// governed by the [`HasArray`] trait. For an array of length 6 (0b110), you'd get an internal
+/// structure like this:
+///
+/// ```text
+/// Array<T, Tape<Tape<B1, B1>, B0>> {
+///     data: ArrayEven<T, ArrayOdd<>> {
+///         left: ArrayOdd<T, ArrayOdd<T, ArrayTerm>> {
+///             left: ArrayOdd<T, ArrayTerm> {
+///                 left: ArrayTerm,
+///                 right: ArrayTerm,
+///                 data: T, // arr[0]
+///             },
+///             right: ArrayOdd<T, ArrayTerm> {
+///                 left: ArrayTerm,
+///                 right: ArrayTerm,
+///                 data: T, // arr[1]
+///             },
+///             data: T, // arr[2]
+///         },
+///         right: ArrayOdd<T, ArrayOdd<T, ArrayTerm>> {
+///             left: ArrayOdd<T, ArrayTerm> {
+///                 left: ArrayTerm,
+///                 right: ArrayTerm,
+///                 data: T, // arr[3]
+///             },
+///             right: ArrayOdd<T, ArrayTerm> {
+///                 left: ArrayTerm,
+///                 right: ArrayTerm,
+///                 data: T, // arr[4]
+///             },
+///             data: T, // arr[5]
+///         },
+///     }
+/// }
+/// ```
+///
+/// Because this is `log_2(B::UNSIGNED)`-depth, we can avoid stack overflows when dropping these
+/// arrays. Each bit of the (trimmed) bitstring adds exactly one level to the tree, whether it's a
+/// zero or a one, so the depth is always the bit length of `N`: the worst case for a given bit
+/// length is no deeper than the best case. For example, an array of length `2^20 - 1` (twenty ones)
+/// has a depth of 20, the same as one of length `2^19`.
+///
+/// # Zero-length arrays
+///
+/// Any bitstring whose value is zero (e.g. [`B0`](crate::B0), or an untrimmed `bs!(0, 0)`) produces
+/// an array with the internal type [`ArrayTerm`], which is a zero-sized type. Such an array has
+/// length 0, its slice views are empty, and iterating over it yields nothing, just like `[T; 0]`.
+///
+/// # Tuple conversions
+///
+/// Small arrays (of lengths 2 to 8) can be built infallibly from tuples of the right arity, with
+/// the length checked at compile-time:
+///
+/// ```
+/// # use typebits::{Array, bs};
+/// let array = Array::<u8, bs!(1, 1)>::from((1, 2, 3));
+/// assert_eq!(array.as_slice(), &[1, 2, 3]);
+/// ```
+///
+/// Using a tuple of the wrong arity won't compile:
+///
+/// ```compile_fail
+/// # use typebits::{Array, bs};
+/// let array = Array::<u8, bs!(1, 1)>::from((1, 2));
+/// ```
+#[repr(transparent)]
+pub struct Array<T, N: Bitstring> {
+    data: <N as HasArray>::ArrayType<T>,
+}
+/// One channel of a two-channel [`Array<T, N>`], which has half as many elements.
+type Channel<T, N> = Array<T, Shr<N, B1>>;
+
+impl<T, N: Bitstring> Array<T, N> {
+    /// The length of this [`Array<T, N>`], which is equal to [`N::UNSIGNED`]. This is the same as
+    /// [`Self::len()`], but can be used anywhere a constant is needed, like the length of a
+    /// regular array.
+    pub const LEN: usize = N::UNSIGNED;
+
+    /// Creates a new [`Array<T, N>`] of uninitialised elements.
+    pub const fn uninit() -> Array<MaybeUninit<T>, N> {
+        // SAFETY: An uninitialized `[MaybeUninit<_>; N]` is valid, same as a regular array. Clippy
+        // can't see through our internal representation to tell that every element is a
+        // `MaybeUninit`, hence the allow.
+        #[allow(clippy::uninit_assumed_init)]
+        unsafe {
+            MaybeUninit::<Array<MaybeUninit<T>, N>>::uninit().assume_init()
+        }
+    }
+
+    /// Creates a new boxed [`Array<T, N>`] of uninitialised elements. You should use this when the
+    /// length `N` is likely to overflow the stack.
+    pub fn uninit_boxed() -> Box<Array<MaybeUninit<T>, N>> {
+        // SAFETY: An uninitialized `[MaybeUninit<_>; N]` is valid, same as a regular array.
+        unsafe { Box::new_uninit().assume_init() }
+    }
+
+    /// Returns a raw pointer to the first element of this [`Array<T, N>`]. Because we have the
+    /// same underlying memory representation as `[T; N::UNSIGNED]`, this pointer is valid for
+    /// reads of [`Self::len()`] (equivalently [`N::UNSIGNED`]) contiguous elements, as long as the
+    /// array isn't moved or mutated in the meantime. For zero-length arrays, this is a dangling
+    /// pointer, which is non-null and aligned, but mustn't be read from.
+    pub const fn as_ptr(&self) -> *const T {
+        // Because of the transparent representation, we can ignore all the zero-sized filler stuff
+        // and just get a direct pointer to a bunch of `T`s. Zero-length arrays are just an
+        // `ArrayTerm`, which isn't aligned for `T`, so we use a dangling pointer there instead.
+        if N::UNSIGNED == 0 {
+            NonNull::<T>::dangling().as_ptr() as *const T
+        } else {
+            self as *const Self as *const T
+        }
+    }
+
+    /// Returns a raw mutable pointer to the first element of this [`Array<T, N>`]. This is valid
+    /// for reads and writes of [`Self::len()`] contiguous elements (see [`Self::as_ptr`]).
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        // See `as_ptr` for zero-length arrays
+        if N::UNSIGNED == 0 {
+            NonNull::<T>::dangling().as_ptr()
+        } else {
+            self as *mut Self as *mut T
+        }
+    }
+
+    /// Gets the contents of this [`Array<T, N>`] as a slice. Because we have the same underlying
+    /// memory representation as a slice, this works. The returned slice is guaranteed to have
+    /// length [`Self::len()`] (equivalently [`N::UNSIGNED`]).
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: We have something in memory that is exactly equivalent to a `[u8; N::UNSIGNED]`.
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), N::UNSIGNED) }
+    }
+
+    /// Gets the contents of this [`Array<T, N>`] as a mutable slice.
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: We have something in memory that is exactly equivalent to a `[u8; N::UNSIGNED]`.
+        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), N::UNSIGNED) }
+    }
+
+    /// Tries to construct an [`Array<T, N>`] from the given slice. This returns a reference, as
+    /// we're just reinterpreting the given slice as our own type. This will fail if the given
+    /// slice has the wrong length.
+    pub const fn try_from_slice(slice: &[T]) -> Result<&Self, BadLength> {
+        if slice.len() != N::UNSIGNED {
+            return Err(BadLength {
+                found: slice.len(),
+                expected: N::UNSIGNED,
+            });
+        }
+
+        // SAFETY: Again, `Array<T, N>` has the same in-memory representation as a slice, so we can
+        // literally just "rename" the type of this slice.
+        Ok(unsafe { &*(slice.as_ptr() as *const Self) })
+    }
+
+    /// Constructs an [`Array<T, N>`] from the given slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given slice is the wrong length.
+    pub const fn from_slice(slice: &[T]) -> &Self {
+        if slice.len() != N::UNSIGNED {
+            panic!("tried to construct array from slice of incorrect length");
+        }
+
+        match Self::try_from_slice(slice) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Tries to construct an [`Array<T, N>`] from the given mutable slice. This returns a mutable
+    /// reference, as we're just reinterpreting the given mutable slice as our own type. This will
+    /// fail if the given slice has the wrong length.
+    pub const fn try_from_mut_slice(slice: &mut [T]) -> Result<&mut Self, BadLength> {
+        if slice.len() != N::UNSIGNED {
+            return Err(BadLength {
+                found: slice.len(),
+                expected: N::UNSIGNED,
+            });
+        }
+
+        // SAFETY: Again, `Array<T, N>` has the same in-memory representation as a slice, so we can
+        // literally just "rename" the type of this slice
+        Ok(unsafe { &mut *(slice.as_mut_ptr() as *mut Self) })
+    }
+
+    /// Constructs an [`Array<T, N>`] from the given mutable slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given slice is the wrong length.
+    pub const fn from_mut_slice(slice: &mut [T]) -> &mut Self {
+        if slice.len() != N::UNSIGNED {
+            panic!("tried to construct array from slice of incorrect length");
+        }
+
+        match Self::try_from_mut_slice(slice) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Gets the contents of this [`Array<T, N>`] as a reference to a const-generic array, without
+    /// copying. This fails to compile if `M` isn't equal to `N::UNSIGNED`:
+    ///
+    /// ```compile_fail
+    /// # use typebits::{Array, bs};
+    /// let array = Array::<u8, bs!(1, 1)>::new();
+    /// let wrong: &[u8; 4] = array.as_array_ref();
+    /// ```
+    pub const fn as_array_ref<const M: usize>(&self) -> &[T; M] {
+        const { assert!(M == N::UNSIGNED, "array length mismatch") };
+
+        // SAFETY: `Array<T, N>` has the same in-memory representation as `[T; N::UNSIGNED]`, which
+        // we've just checked is `[T; M]`
+        unsafe { &*(self.as_slice().as_ptr() as *const [T; M]) }
+    }
+
+    /// Gets the contents of this [`Array<T, N>`] as a mutable reference to a const-generic array,
+    /// without copying. This fails to compile if `M` isn't equal to `N::UNSIGNED`.
+    pub const fn as_array_mut<const M: usize>(&mut self) -> &mut [T; M] {
+        const { assert!(M == N::UNSIGNED, "array length mismatch") };
+
+        // SAFETY: As above
+        unsafe { &mut *(self.as_mut_slice().as_mut_ptr() as *mut [T; M]) }
+    }
+
+    /// Gets the contents of this [`Array<T, N>`] as an array of chunks of length `C`, without
+    /// copying. If `C` doesn't evenly divide `N`, the elements that don't fit into a whole chunk at
+    /// the end are excluded (see [`Self::chunks_remainder`]). This fails to compile if `C` is zero.
+    pub const fn chunks_array<C: Bitstring>(&self) -> &Array<Array<T, C>, Quotient<N, C>> {
+        const { assert!(C::UNSIGNED != 0, "chunk length must be non-zero") };
+
+        // SAFETY: `Array<Array<T, C>, Q>` has the same in-memory representation as
+        // `[T; Q::UNSIGNED * C::UNSIGNED]`, and `Q` is chosen so that's no longer than `N`
+        unsafe { &*(self.as_slice().as_ptr() as *const Array<Array<T, C>, Quotient<N, C>>) }
+    }
+
+    /// Gets the contents of this [`Array<T, N>`] as a mutable array of chunks of length `C`,
+    /// without copying. See [`Self::chunks_array`] for details.
+    pub const fn chunks_array_mut<C: Bitstring>(
+        &mut self,
+    ) -> &mut Array<Array<T, C>, Quotient<N, C>> {
+        const { assert!(C::UNSIGNED != 0, "chunk length must be non-zero") };
+
+        // SAFETY: As above
+        unsafe {
+            &mut *(self.as_mut_slice().as_mut_ptr() as *mut Array<Array<T, C>, Quotient<N, C>>)
+        }
+    }
+
+    /// Gets the chunk at index `i` of this [`Array<T, N>`], when it's split into chunks of length
+    /// `C`, without copying. This will return `None` if `i` is out of bounds, or if `C` doesn't
+    /// evenly divide `N` (so that there's no question of whether or not a partial last chunk
+    /// should be returned). This fails to compile if `C` is zero.
+    pub fn chunk<C: Bitstring>(&self, i: usize) -> Option<&Array<T, C>> {
+        const { assert!(C::UNSIGNED != 0, "chunk length must be non-zero") };
+
+        if N::UNSIGNED % C::UNSIGNED != 0 {
+            return None;
+        }
+        self.as_slice()
+            .chunks_exact(C::UNSIGNED)
+            .nth(i)
+            .map(Array::from_slice)
+    }
+
+    /// Gets the chunk at index `i` of this [`Array<T, N>`] as a mutable array. See
+    /// [`Self::chunk`] for details.
+    pub fn chunk_mut<C: Bitstring>(&mut self, i: usize) -> Option<&mut Array<T, C>> {
+        const { assert!(C::UNSIGNED != 0, "chunk length must be non-zero") };
+
+        if N::UNSIGNED % C::UNSIGNED != 0 {
+            return None;
+        }
+        self.as_mut_slice()
+            .chunks_exact_mut(C::UNSIGNED)
+            .nth(i)
+            .map(Array::from_mut_slice)
+    }
+
+    /// Gets the elements of this [`Array<T, N>`] that would be left over after splitting it into
+    /// chunks of length `C` (i.e. the last `N % C` elements). This fails to compile if `C` is
+    /// zero.
+    pub const fn chunks_remainder<C: Bitstring>(&self) -> &[T] {
+        const { assert!(C::UNSIGNED != 0, "chunk length must be non-zero") };
+
+        self.as_slice()
+            .split_at(Quotient::<N, C>::UNSIGNED * C::UNSIGNED)
+            .1
+    }
+
+    /// Gets the elements of this [`Array<T, N>`] that would be left over after splitting it into
+    /// chunks of length `C` as a mutable slice. See [`Self::chunks_remainder`] for details.
+    pub const fn chunks_remainder_mut<C: Bitstring>(&mut self) -> &mut [T] {
+        const { assert!(C::UNSIGNED != 0, "chunk length must be non-zero") };
+
+        self.as_mut_slice()
+            .split_at_mut(Quotient::<N, C>::UNSIGNED * C::UNSIGNED)
+            .1
+    }
+
+    /// Returns an iterator over every overlapping window of length `W` in this [`Array<T, N>`],
+    /// as typed arrays rather than slices. Like [`slice::windows`], this will produce nothing if
+    /// `W` is longer than the array, and it will panic if `W` is zero.
+    pub fn windows_array<'a, W: Bitstring + 'a>(&'a self) -> impl Iterator<Item = &'a Array<T, W>> {
+        self.as_slice().windows(W::UNSIGNED).map(Array::from_slice)
+    }
+
+    /// Applies the given function to every overlapping window of the given runtime length in this
+    /// [`Array<T, N>`], in order, and collects the results. Like [`slice::windows`], this will
+    /// produce nothing if `size` is longer than the array, and it will panic if `size` is zero.
+    pub fn windows_reduce<U, F: FnMut(&[T]) -> U>(&self, size: usize, f: F) -> Vec<U> {
+        self.as_slice().windows(size).map(f).collect()
+    }
+
+    /// Returns an iterator over chunks of this [`Array<T, N>`] of the given runtime length. Unlike
+    /// [`slice::chunks_exact`], this won't silently ignore leftover elements: if the chunk size
+    /// doesn't evenly divide `N`, this will fail, with the error reporting the array's length and
+    /// the nearest multiple of `size` (rounding down on ties) that would have worked. Like
+    /// [`slice::chunks_exact`], this will panic if `size` is zero.
+    pub fn try_chunks(&self, size: usize) -> Result<std::slice::ChunksExact<'_, T>, BadLength> {
+        let chunks = self.as_slice().chunks_exact(size);
+        let remainder = chunks.remainder().len();
+        if remainder != 0 {
+            let below = N::UNSIGNED - remainder;
+            return Err(BadLength {
+                found: N::UNSIGNED,
+                expected: if remainder <= size - remainder {
+                    below
+                } else {
+                    below + size
+                },
+            });
+        }
+
+        Ok(chunks)
+    }
+
+    /// Returns the length of this [`Array<T, N>`], which is equal to [`N::UNSIGNED`].
+    pub const fn len() -> usize {
+        N::UNSIGNED
+    }
+
+    /// Returns a reference to the first element of this [`Array<T, N>`], or `None` if it has
+    /// length 0.
+    pub const fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Returns a reference to the last element of this [`Array<T, N>`], or `None` if it has length
+    /// 0.
+    pub const fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Returns an iterator over references to the elements of this [`Array<T, N>`].
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of this [`Array<T, N>`].
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Calls the given function on every element of this [`Array<T, N>`] in order, along with its
+    /// index, allowing it to mutate the element in place.
+    pub fn for_each_mut<F: FnMut(usize, &mut T)>(&mut self, mut f: F) {
+        for (i, elem) in self.iter_mut().enumerate() {
+            f(i, elem);
+        }
+    }
+
+    /// Returns the index of the first element of this [`Array<T, N>`] matching the given
+    /// predicate, if there is one. This is the same as [`Iterator::position`].
+    pub fn position<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+        self.iter().position(f)
+    }
+    /// Returns the index of the last element of this [`Array<T, N>`] matching the given predicate,
+    /// if there is one. This is the same as [`Iterator::rposition`].
+    pub fn rposition<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+        self.iter().rposition(f)
+    }
+    /// Returns the first element of this [`Array<T, N>`] matching the given predicate, if there
+    /// is one. This is the same as [`Iterator::find`].
+    pub fn find<F: FnMut(&&T) -> bool>(&self, f: F) -> Option<&T> {
+        self.iter().find(f)
+    }
+    /// Returns whether or not any element of this [`Array<T, N>`] matches the given predicate.
+    /// This is the same as [`Iterator::any`], so it's false for empty arrays.
+    pub fn any<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().any(f)
+    }
+    /// Returns whether or not every element of this [`Array<T, N>`] matches the given predicate.
+    /// This is the same as [`Iterator::all`], so it's true for empty arrays.
+    pub fn all<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().all(f)
+    }
+
+    /// Folds every element of this [`Array<T, N>`] into an accumulator, in order. This is the same
+    /// as [`Iterator::fold`].
+    pub fn fold<Acc, F: FnMut(Acc, &T) -> Acc>(&self, init: Acc, f: F) -> Acc {
+        self.iter().fold(init, f)
+    }
+
+    /// Folds every element of this [`Array<T, N>`] into an accumulator, in order, stopping at the
+    /// first error returned by `f`. This is like [`Iterator::try_fold`], specialised to
+    /// [`Result`].
+    pub fn try_fold<Acc, E, F: FnMut(Acc, &T) -> Result<Acc, E>>(
+        &self,
+        init: Acc,
+        f: F,
+    ) -> Result<Acc, E> {
+        self.iter().try_fold(init, f)
+    }
+
+    /// Returns mutable references to the elements at each of the given indices simultaneously, or
+    /// `None` if any of the indices are out of bounds or if any two of them are the same.
+    pub fn get_disjoint_mut<const K: usize>(&mut self, indices: [usize; K]) -> Option<[&mut T; K]> {
+        self.as_mut_slice().get_disjoint_mut(indices).ok()
+    }
+
+    /// Swaps all the elements of this [`Array<T, N>`] with those of the given slice. This will
+    /// panic if the slice's length isn't `N::UNSIGNED`.
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        self.as_mut_slice().swap_with_slice(other);
+    }
+
+    /// Overwrites the elements of this [`Array<T, N>`] in order with items from the given
+    /// iterator, dropping the elements they replace, and returns how many were written. This
+    /// stops after `N::UNSIGNED` items, and if the iterator runs out first, the rest of the array
+    /// is left as it was.
+    pub fn fill_from_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut written = 0;
+        for (slot, item) in self.iter_mut().zip(iter) {
+            *slot = item;
+            written += 1;
+        }
+        written
+    }
+
+    /// Maps each element of this [`Array<T, N>`] by reference into a new [`Array<U, N>`], without
+    /// consuming this array. If `f` panics, any elements already produced are dropped.
+    pub fn map_ref<U, F: FnMut(&T) -> U>(&self, mut f: F) -> Array<U, N> {
+        let mut uninit = Array::<U, N>::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for elem in self.iter() {
+            guard.push(f(elem));
+        }
+        guard.finish();
+
+        // SAFETY: The guard has initialised every element
+        unsafe { uninit.assume_init() }
+    }
+
+    /// Maps each element of this [`Array<T, N>`] by reference with the given fallible function
+    /// into a new [`Array<U, N>`], without consuming this array. This stops at the first error,
+    /// dropping any elements that have already been produced, and returns it.
+    pub fn try_map_ref<U, E, F: FnMut(&T) -> Result<U, E>>(
+        &self,
+        mut f: F,
+    ) -> Result<Array<U, N>, E> {
+        let mut uninit = Array::<U, N>::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for elem in self.iter() {
+            // Returning early drops the guard, and with it everything we've made so far
+            guard.push(f(elem)?);
+        }
+        guard.finish();
+
+        // SAFETY: The guard has initialised every element
+        Ok(unsafe { uninit.assume_init() })
+    }
+
+    /// Creates a new [`Array<T, N>`] where each element is the result of calling the given
+    /// function with its index.
+    pub fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        let mut uninit = Self::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for i in 0..N::UNSIGNED {
+            guard.push(f(i));
+        }
+        guard.finish();
+
+        // SAFETY: The guard has initialised every element
+        unsafe { uninit.assume_init() }
+    }
+
+    /// Creates a new [`Array<T, N>`] by moving in the elements of the given regular array, in
+    /// order. Unlike the [`TryFrom`] conversion, the lengths are checked at compile time, so this
+    /// can't fail at runtime, and `M` not being `N::UNSIGNED` won't compile:
+    ///
+    /// ```compile_fail
+    /// # use typebits::{Array, bs};
+    /// let array = Array::<u8, bs!(1, 1)>::from_array_iter([1, 2]);
+    /// ```
+    pub fn from_array_iter<const M: usize>(array: [T; M]) -> Self {
+        const { assert!(M == N::UNSIGNED, "array length mismatch") };
+
+        // SAFETY: `Array<T, N>` has the same in-memory representation as `[T; N::UNSIGNED]`, and
+        // we've just checked that's the same as `[T; M]`
+        unsafe { const_transmute::<_, Self>(array) }
+    }
+
+    /// Creates a new [`Array<T, N>`] where each element is the result of calling the given
+    /// fallible function with its index. This stops at the first error, dropping any elements
+    /// that have already been created, and returns it.
+    pub fn try_from_fn<E, F: FnMut(usize) -> Result<T, E>>(mut f: F) -> Result<Self, E> {
+        let mut uninit = Self::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for i in 0..N::UNSIGNED {
+            // Returning early drops the guard, and with it everything we've made so far
+            guard.push(f(i)?);
+        }
+        guard.finish();
+
+        // SAFETY: The guard has initialised every element
+        Ok(unsafe { uninit.assume_init() })
+    }
+
+    /// Creates a new boxed [`Array<T, N>`] where each element is the result of calling the given
+    /// function with its index. You should use this when the length `N` is likely to overflow the
+    /// stack.
+    pub fn from_fn_boxed<F: FnMut(usize) -> T>(mut f: F) -> Box<Self> {
+        let mut uninit = Self::uninit_boxed();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for i in 0..N::UNSIGNED {
+            guard.push(f(i));
+        }
+        guard.finish();
+
+        // SAFETY: There's no difference between `MaybeUninit<T>` and `T` in memory (literally a
+        // union with `()`), so perfectly safe to reinterpret the array as a whole
+        unsafe { const_transmute::<_, Box<Self>>(uninit) }
+    }
+
+    /// Moves this [`Array<T, N>`] onto the heap. Note that this will copy the array through the
+    /// stack once, so it's only suitable for arrays that already fit there: for arrays too large
+    /// for the stack, build them on the heap directly with [`Self::new_boxed`],
+    /// [`Self::from_fn_boxed`], or [`Self::uninit_boxed`].
+    pub fn boxed(self) -> Box<Self> {
+        Box::new(self)
+    }
+
+    /// Converts this boxed [`Array<T, N>`] into a boxed slice of length `N::UNSIGNED`, reusing
+    /// the same allocation.
+    pub fn into_boxed_slice(self: Box<Self>) -> Box<[T]> {
+        // Zero-length arrays have no allocation, but their pointers may not be aligned for `T`
+        if N::UNSIGNED == 0 {
+            return Box::new([]);
+        }
+
+        let ptr = Box::into_raw(self);
+        // SAFETY: `Array<T, N>` has the same in-memory representation (and therefore the same
+        // layout) as `[T; N::UNSIGNED]`, so the allocation can be managed as a slice of that
+        // length from here
+        unsafe {
+            Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+                ptr.cast::<T>(),
+                N::UNSIGNED,
+            ))
+        }
+    }
+
+    /// Removes the element at index `I` from this [`Array<T, N>`], replacing it with the last
+    /// element, and returns it along with the remaining array, which is one element shorter. This
+    /// doesn't preserve ordering, but it's O(1). This fails to compile if `I` is out of bounds:
+    ///
+    /// ```compile_fail
+    /// # use typebits::{Array, bs};
+    /// let array = Array::<u8, bs!(1, 1)>::new();
+    /// let (elem, rest) = array.swap_remove::<bs!(1, 1)>();
+    /// ```
+    pub fn swap_remove<I: Bitstring>(self) -> (T, Array<T, Dec<N>>) {
+        const { assert!(I::UNSIGNED < N::UNSIGNED, "index out of bounds") };
+
+        let last = N::UNSIGNED - 1;
+        // We're moving every element out, so the original must never drop them
+        let mut array = ManuallyDrop::new(self);
+        let ptr = array.as_mut_slice().as_mut_ptr();
+        // SAFETY: `I` and `last` are in bounds, and we read each element exactly once (the last
+        // element is moved into the gap, and read from there). The remaining array has the same
+        // in-memory representation as `[T; N::UNSIGNED - 1]`, which are the first elements of
+        // the original.
+        unsafe {
+            let elem = ptr.add(I::UNSIGNED).read();
+            if I::UNSIGNED != last {
+                ptr.add(I::UNSIGNED).write(ptr.add(last).read());
+            }
+            let rest = ptr.cast::<Array<T, Dec<N>>>().read();
+            (elem, rest)
+        }
+    }
+
+    /// Returns an iterator that moves the elements out of this [`Array<T, N>`] from back to
+    /// front. This is the same as `array.into_iter().rev()`.
+    pub fn into_iter_rev(self) -> Rev<ArrayIntoIter<T, N>> {
+        self.into_iter().rev()
+    }
+
+    /// Maps the elements of this [`Array<T, N>`] in order with the given function, stopping at the
+    /// first element for which it returns `None`. As the number of elements mapped isn't known
+    /// until runtime, the results are collected into a [`Vec`].
+    pub fn map_while<U, F: FnMut(&T) -> Option<U>>(&self, f: F) -> Vec<U> {
+        self.iter().map_while(f).collect()
+    }
+
+    /// Maps each element of this [`Array<T, N>`] by reference with the given function, collecting
+    /// the results into any [`FromIterator`] type (e.g. a [`Vec`] or a
+    /// [`HashSet`](std::collections::HashSet)) rather than another array.
+    pub fn map_collect<B: FromIterator<U>, U, F: FnMut(&T) -> U>(&self, f: F) -> B {
+        self.iter().map(f).collect()
+    }
+
+    /// Splits this [`Array<T, N>`] into a head of the first `H::UNSIGNED` elements and a tail of
+    /// the rest, moving the elements without cloning them. This fails to compile if `H` is longer
+    /// than the array:
+    ///
+    /// ```compile_fail
+    /// # use typebits::{Array, bs};
+    /// let array = Array::<u8, bs!(1, 1)>::new();
+    /// let (head, tail) = array.split_head::<bs!(1, 0, 0)>();
+    /// ```
+    pub fn split_head<H: Bitstring>(self) -> (Array<T, H>, Array<T, Diff<N, H>>) {
+        const { assert!(H::UNSIGNED <= N::UNSIGNED, "split point out of bounds") };
+
+        // We're moving every element out, so the original must never drop them
+        let array = ManuallyDrop::new(self);
+        let ptr = array.as_slice().as_ptr();
+        // SAFETY: The head and tail have the same in-memory representations as
+        // `[T; H::UNSIGNED]` and `[T; N::UNSIGNED - H::UNSIGNED]`, which together exactly cover
+        // the original array. Each element is read exactly once, and the original won't be
+        // dropped.
+        unsafe {
+            let head = ptr.cast::<Array<T, H>>().read();
+            let tail = ptr.add(H::UNSIGNED).cast::<Array<T, Diff<N, H>>>().read();
+            (head, tail)
+        }
+    }
+
+    /// Rotates the elements of this [`Array<T, N>`] left by `By::UNSIGNED` places (wrapping
+    /// around `N`), so the element at index `By::UNSIGNED % N::UNSIGNED` becomes the first. This is
+    /// the same as [`slice::rotate_left`], but with the amount fixed in the type, and rotating by
+    /// any multiple of `N` leaves the array unchanged.
+    pub fn rotate_const<By: Bitstring>(mut self) -> Array<T, N> {
+        // Rotating a zero-length array is always a no-op, so there's nothing to take the modulus of
+        let by = const {
+            if N::UNSIGNED == 0 {
+                0
+            } else {
+                By::UNSIGNED % N::UNSIGNED
+            }
+        };
+        self.as_mut_slice().rotate_left(by);
+        self
+    }
+
+    /// Splits this [`Array<T, N>`] of interleaved two-channel data (e.g. stereo samples) into its
+    /// two channels, so the elements at even indices go into the first array, and those at odd
+    /// indices into the second, keeping their order. This is the inverse of
+    /// [`Self::interleave`]. `N` must be even, otherwise this will fail to compile:
+    ///
+    /// ```compile_fail
+    /// # use typebits::{Array, bs};
+    /// let array = Array::<u8, bs!(1, 0, 1)>::new();
+    /// let (left, right) = array.deinterleave();
+    /// ```
+    pub fn deinterleave(self) -> (Channel<T, N>, Channel<T, N>) {
+        const { assert!(N::UNSIGNED % 2 == 0, "array length must be even") };
+
+        let mut left = Channel::<T, N>::uninit();
+        let mut right = Channel::<T, N>::uninit();
+        // The elements are moved into the channels, so the original must never drop them
+        let array = ManuallyDrop::new(self);
+        let src = array.as_slice().as_ptr();
+        for (i, (l, r)) in left
+            .as_mut_slice()
+            .iter_mut()
+            .zip(right.as_mut_slice())
+            .enumerate()
+        {
+            // SAFETY: Each channel has half as many elements as the original, so these are in
+            // bounds, and each element is read exactly once
+            unsafe {
+                l.write(src.add(2 * i).read());
+                r.write(src.add(2 * i + 1).read());
+            }
+        }
+
+        // SAFETY: We've just written every element of both channels
+        unsafe { (left.assume_init(), right.assume_init()) }
+    }
+
+    /// Interleaves this [`Array<T, N>`] with another of the same length, as two-channel data
+    /// (e.g. stereo samples), so the result alternates between an element of this array and one
+    /// of `other`, starting with this one. This is the inverse of [`Self::deinterleave`].
+    pub fn interleave(self, other: Self) -> Array<T, Shl<N, B1>> {
+        let mut interleaved = Array::<T, Shl<N, B1>>::uninit();
+        // The elements are moved into the new array, so the originals must never drop them
+        let left = ManuallyDrop::new(self);
+        let right = ManuallyDrop::new(other);
+        let (l, r) = (left.as_slice().as_ptr(), right.as_slice().as_ptr());
+        for (i, pair) in interleaved.as_mut_slice().chunks_exact_mut(2).enumerate() {
+            // SAFETY: There's one pair for each element of the inputs, so these are in bounds,
+            // and each element is read exactly once
+            unsafe {
+                pair[0].write(l.add(i).read());
+                pair[1].write(r.add(i).read());
+            }
+        }
+
+        // SAFETY: We've just written every element
+        unsafe { interleaved.assume_init() }
+    }
+}
+impl<T, C: Bitstring, R: Bitstring> Array<Array<T, C>, R> {
+    /// Gets the contents of this nested array as a single flat slice of length
+    /// `C::UNSIGNED * R::UNSIGNED`, with each inner array one after the other (so a nested array
+    /// of rows will be flattened in row-major order). This doesn't copy anything.
+    pub fn as_flattened(&self) -> &[T] {
+        let len = C::UNSIGNED * R::UNSIGNED;
+        // Zero-length inner arrays aren't aligned for `T`, so we can't make a slice from them
+        if len == 0 {
+            return &[];
+        }
+
+        // SAFETY: Each inner array has the same representation as `[T; C::UNSIGNED]`, and the
+        // outer one lays them out contiguously, so this is just `len` contiguous `T`s
+        unsafe { std::slice::from_raw_parts(self.as_ptr().cast::<T>(), len) }
+    }
+    /// Gets the contents of this nested array as a single flat mutable slice. See
+    /// [`Self::as_flattened`] for details.
+    pub fn as_flattened_mut(&mut self) -> &mut [T] {
+        let len = C::UNSIGNED * R::UNSIGNED;
+        if len == 0 {
+            return &mut [];
+        }
+
+        // SAFETY: As above
+        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr().cast::<T>(), len) }
+    }
+}
+impl<T, N: Bitstring> Array<MaybeUninit<T>, N> {
+    /// Assumes this array of [`MaybeUninit<T>`] has all elements initialized.
+    ///
+    /// # Safety
+    ///
+    /// All elements must actually be initialized, or this will lead to undefined behaviour due to
+    /// the underlying transmutation.
+    ///
+    /// # Boxes
+    ///
+    /// If you're trying to interpret a `Box<Array<MaybeUninit<T>, N>>`, you'll need
+    pub const unsafe fn assume_init(self) -> Array<T, N> {
+        // SAFETY: There's no difference between `MaybeUninit<T>` and `T` in memory (literally a
+        // union with `()`), so perfectly safe to reinterpret the array as a whole
+        unsafe { const_transmute::<_, _>(self) }
+    }
+}
+impl<T: Default, N: Bitstring> Array<T, N> {
+    /// Creates a new [`Array<T, N>`] with all elements set to `T::default()`.
+    pub fn new() -> Self {
+        let mut uninit = Self::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for _ in 0..N::UNSIGNED {
+            guard.push(T::default());
+        }
+        guard.finish();
+
+        // SAFETY: There's no difference between `MaybeUninit<T>` and `T` in memory (literally a
+        // union with `()`), so perfectly safe to reinterpret the array as a whole
+        unsafe { const_transmute::<_, Self>(uninit) }
+    }
+
+    /// Creates a new boxed [`Array<T, N>`] with all elements set to `T::default()`. You should use
+    /// this when the length `N` is likely to overflow the stack.
+    pub fn new_boxed() -> Box<Self> {
+        let mut uninit = Self::uninit_boxed();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for _ in 0..N::UNSIGNED {
+            guard.push(T::default());
+        }
+        guard.finish();
+
+        // SAFETY: There's no difference between `MaybeUninit<T>` and `T` in memory (literally a
+        // union with `()`), so perfectly safe to reinterpret the array as a whole
+        unsafe { const_transmute::<_, Box<Self>>(uninit) }
+    }
+
+    /// Converts this [`Array<T, N>`] into an array of a different length `M`, keeping the first
+    /// elements in order. If the new length is shorter, any excess elements will be dropped, and
+    /// if it's longer, the new elements will be set to `T::default()`.
+    pub fn resize<M: Bitstring>(self) -> Array<T, M> {
+        let kept = N::UNSIGNED.min(M::UNSIGNED);
+
+        // We're moving or dropping every element ourselves, so the original must never drop them
+        let mut array = ManuallyDrop::new(self);
+        let ptr = array.as_mut_slice().as_mut_ptr();
+        let mut uninit = Array::<T, M>::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for i in 0..kept {
+            // SAFETY: `i` is in bounds, and each element is read exactly once
+            guard.push(unsafe { ptr.add(i).read() });
+        }
+        // SAFETY: These elements are in bounds and haven't been moved out, and the original will
+        // never touch them again
+        unsafe {
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(
+                ptr.add(kept),
+                N::UNSIGNED - kept,
+            ))
+        };
+        for _ in kept..M::UNSIGNED {
+            guard.push(T::default());
+        }
+        guard.finish();
+
+        // SAFETY: The guard has initialised every element
+        unsafe { uninit.assume_init() }
+    }
+}
+impl<T: Zeroable, N: Bitstring> Array<T, N> {
+    /// Creates a new [`Array<T, N>`] with every element set to zero. Unlike [`Self::new`], this
+    /// doesn't write each element individually, it just zeroes the whole buffer in one go, which
+    /// is much faster for large numeric arrays.
+    pub const fn zeroed() -> Self {
+        // SAFETY: `T` is valid as all zero bytes, and our internal types have no other fields
+        unsafe { MaybeUninit::<Self>::zeroed().assume_init() }
+    }
+
+    /// Creates a new boxed [`Array<T, N>`] with every element set to zero (see [`Self::zeroed`]).
+    /// You should use this when the length `N` is likely to overflow the stack.
+    pub fn zeroed_boxed() -> Box<Self> {
+        // SAFETY: As above
+        unsafe { Box::new_zeroed().assume_init() }
+    }
+}
+impl<T: Default, N: Bitstring> Default for Array<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+// Anything that can index a slice (single positions and all the range types) can index an array
+impl<T, N: Bitstring, I: SliceIndex<[T]>> Index<I> for Array<T, N> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+impl<T, N: Bitstring, I: SliceIndex<[T]>> IndexMut<I> for Array<T, N> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+// This formats exactly like a slice, so nested arrays will print as lists of lists, and alternate
+// formatting (`{:#?}`) will put each inner array on its own lines
+impl<T: fmt::Debug, N: Bitstring> fmt::Debug for Array<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T: PartialEq, N: Bitstring> PartialEq for Array<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl<T: Eq, N: Bitstring> Eq for Array<T, N> {}
+// This must hash in exactly the same way as a slice for our `Borrow<[T]>` implementation to be
+// valid
+impl<T: Hash, N: Bitstring> Hash for Array<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+impl<T, N: Bitstring> AsRef<[T]> for Array<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, N: Bitstring> AsMut<[T]> for Array<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+impl<T, N: Bitstring> From<Box<Array<T, N>>> for Box<[T]> {
+    fn from(array: Box<Array<T, N>>) -> Self {
+        array.into_boxed_slice()
+    }
+}
+impl<T, N: Bitstring> Borrow<[T]> for Array<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, N: Bitstring> BorrowMut<[T]> for Array<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+impl<'a, T, N: Bitstring> IntoIterator for &'a Array<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, T, N: Bitstring> IntoIterator for &'a mut Array<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+impl<T, N: Bitstring> IntoIterator for Array<T, N> {
+    type Item = T;
+    type IntoIter = ArrayIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArrayIntoIter {
+            // SAFETY: There's no difference between `MaybeUninit<T>` and `T` in memory, and every
+            // element is initialised to start with
+            data: unsafe { const_transmute::<_, Array<MaybeUninit<T>, N>>(self) },
+            alive: 0..N::UNSIGNED,
+        }
+    }
+}
+
+/// An iterator that moves out of an [`Array<T, N>`]. Any elements not yielded will be dropped
+/// along with the iterator.
+pub struct ArrayIntoIter<T, N: Bitstring> {
+    data: Array<MaybeUninit<T>, N>,
+    /// The indices of the elements that are still initialised, and haven't been yielded yet.
+    alive: Range<usize>,
+}
+impl<T, N: Bitstring> Iterator for ArrayIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.alive.next()?;
+        // SAFETY: Every index in `alive` is initialised, and we've just removed this one, so it
+        // won't be read again
+        Some(unsafe { self.data.as_slice()[idx].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.alive.size_hint()
+    }
+}
+impl<T, N: Bitstring> DoubleEndedIterator for ArrayIntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.alive.next_back()?;
+        // SAFETY: As above
+        Some(unsafe { self.data.as_slice()[idx].assume_init_read() })
+    }
+}
+impl<T, N: Bitstring> ExactSizeIterator for ArrayIntoIter<T, N> {}
+impl<T, N: Bitstring> FusedIterator for ArrayIntoIter<T, N> {}
+impl<T, N: Bitstring> Drop for ArrayIntoIter<T, N> {
+    fn drop(&mut self) {
+        let alive = self.alive.clone();
+        for elem in &mut self.data.as_mut_slice()[alive] {
+            // SAFETY: Every element in `alive` is initialised, and will never be touched again
+            unsafe { elem.assume_init_drop() };
+        }
+    }
+}
+
+// Tuples have statically-known lengths, so we can convert from them infallibly for small arities
+macro_rules! impl_from_tuple {
+    ($($len:ty => ($($elem:ident),+)),+ $(,)?) => {
+        $(
+            impl<T> From<($(impl_from_tuple!(@elem $elem),)+)> for Array<T, $len> {
+                fn from(($($elem,)+): ($(impl_from_tuple!(@elem $elem),)+)) -> Self {
+                    Self::from_array_iter([$($elem),+])
+                }
+            }
+        )+
+    };
+    (@elem $elem:ident) => { T };
+}
+impl_from_tuple!(
+    crate::bs!(1, 0) => (a, b),
+    crate::bs!(1, 1) => (a, b, c),
+    crate::bs!(1, 0, 0) => (a, b, c, d),
+    crate::bs!(1, 0, 1) => (a, b, c, d, e),
+    crate::bs!(1, 1, 0) => (a, b, c, d, e, f),
+    crate::bs!(1, 1, 1) => (a, b, c, d, e, f, g),
+    crate::bs!(1, 0, 0, 0) => (a, b, c, d, e, f, g, h),
+);
+
+// Whether or not a bitstring and a const generic length are equal can't generally be proven
+// statically, so these conversions check the lengths at runtime
+impl<T, N: Bitstring, const M: usize> TryFrom<Array<T, N>> for [T; M] {
+    type Error = BadLength;
+
+    fn try_from(array: Array<T, N>) -> Result<Self, Self::Error> {
+        if N::UNSIGNED != M {
+            return Err(BadLength {
+                found: N::UNSIGNED,
+                expected: M,
+            });
+        }
+
+        // SAFETY: `Array<T, N>` has the same in-memory representation as `[T; N::UNSIGNED]`, and
+        // we've just checked that's the same as `[T; M]`
+        Ok(unsafe { const_transmute::<_, Self>(array) })
+    }
+}
+impl<T, N: Bitstring, const M: usize> TryFrom<[T; M]> for Array<T, N> {
+    type Error = BadLength;
+
+    fn try_from(array: [T; M]) -> Result<Self, Self::Error> {
+        if N::UNSIGNED != M {
+            return Err(BadLength {
+                found: M,
+                expected: N::UNSIGNED,
+            });
+        }
+
+        // SAFETY: As above
+        Ok(unsafe { const_transmute::<_, Self>(array) })
+    }
+}
+impl<T: Clone, N: Bitstring> Clone for Array<T, N> {
+    fn clone(&self) -> Self {
+        let mut uninit = Self::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for elem in self.iter() {
+            guard.push(elem.clone());
+        }
+        guard.finish();
+
+        // SAFETY: We've initialised all elements
+        unsafe { uninit.assume_init() }
+    }
+}
+impl<T: Clone, N: Bitstring> Array<T, N> {
+    pub fn try_new_from_slice(slice: &[T]) -> Result<Self, BadLength> {
+        if slice.len() != N::UNSIGNED {
+            return Err(BadLength {
+                found: slice.len(),
+                expected: N::UNSIGNED,
+            });
+        }
+
+        let mut uninit = Self::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for elem in slice {
+            guard.push(elem.clone());
+        }
+        guard.finish();
+
+        Ok(unsafe { uninit.assume_init() })
+    }
+
+    pub fn new_from_slice(slice: &[T]) -> Self {
+        if slice.len() != N::UNSIGNED {
+            panic!("tried to construct array from slice of incorrect length");
+        }
+
+        match Self::try_new_from_slice(slice) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Tries to construct a new [`Array<T, N>`] by cloning the elements of all the given slices,
+    /// one after the other. This will fail if the slices don't have exactly `N::UNSIGNED`
+    /// elements in total.
+    pub fn try_new_from_slices(slices: &[&[T]]) -> Result<Self, BadLength> {
+        let total_len = slices.iter().map(|slice| slice.len()).sum();
+        if total_len != N::UNSIGNED {
+            return Err(BadLength {
+                found: total_len,
+                expected: N::UNSIGNED,
+            });
+        }
+
+        let mut uninit = Self::uninit();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for elem in slices.iter().flat_map(|slice| slice.iter()) {
+            guard.push(elem.clone());
+        }
+        guard.finish();
+
+        // SAFETY: We've initialised all elements
+        Ok(unsafe { uninit.assume_init() })
+    }
+
+    /// Constructs a new [`Array<T, N>`] by cloning the elements of all the given slices, one after
+    /// the other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices don't have exactly `N::UNSIGNED` elements in total.
+    pub fn new_from_slices(slices: &[&[T]]) -> Self {
+        match Self::try_new_from_slices(slices) {
+            Ok(s) => s,
+            Err(_) => panic!("tried to construct array from slices of incorrect total length"),
+        }
+    }
+
+    pub fn try_new_boxed_from_slice(slice: &[T]) -> Result<Box<Self>, BadLength> {
+        if slice.len() != N::UNSIGNED {
+            return Err(BadLength {
+                found: slice.len(),
+                expected: N::UNSIGNED,
+            });
+        }
+
+        let mut uninit = Self::uninit_boxed();
+        let mut guard = FillGuard::new(uninit.as_mut_slice());
+        for elem in slice {
+            guard.push(elem.clone());
+        }
+        guard.finish();
+
+        // SAFETY: There's no difference between `MaybeUninit<T>` and `T` in memory (literally a
+        // union with `()`), so perfectly safe to reinterpret the array as a whole
+        Ok(unsafe { const_transmute::<_, _>(uninit) })
+    }
+
+    pub fn new_boxed_from_slice(slice: &[T]) -> Box<Self> {
+        if slice.len() != N::UNSIGNED {
+            panic!("tried to construct array from slice of incorrect length");
+        }
+
+        match Self::try_new_boxed_from_slice(slice) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+impl<T: Copy, N: Bitstring> Array<T, N> {
+    /// Copies all the elements of the given slice into this [`Array<T, N>`] with a single
+    /// `memcpy`. This will panic if the slice's length isn't `N::UNSIGNED`.
+    pub fn copy_from_slice(&mut self, src: &[T]) {
+        self.as_mut_slice().copy_from_slice(src);
+    }
+
+    /// Copies the elements in the range `src` to the position starting at `dest` within this
+    /// [`Array<T, N>`], with a single `memmove`, so the two regions may overlap. This will panic if
+    /// either region is out of bounds.
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
+        self.as_mut_slice().copy_within(src, dest);
+    }
+
+    /// Moves every element matching the given predicate to the front of this [`Array<T, N>`], in
+    /// order, and returns how many there were. Elements past that prefix are left as they were,
+    /// so they may include copies of retained elements or ones that didn't match.
+    pub fn retain_prefix<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let slice = self.as_mut_slice();
+        let mut retained = 0;
+        for i in 0..slice.len() {
+            if pred(&slice[i]) {
+                // We'll never have retained more than we've checked, so this never overwrites an
+                // element we've yet to check
+                slice[retained] = slice[i];
+                retained += 1;
+            }
+        }
+        retained
+    }
+}
+
+/// An internal guard for filling a slice of uninitialised elements in order. If this is dropped
+/// before [`FillGuard::finish`] is called (e.g. because a panic unwound through it), it will drop
+/// every element that has been initialised so far, so nothing is leaked.
+struct FillGuard<'a, T> {
+    slice: &'a mut [MaybeUninit<T>],
+    initialized: usize,
+}
+impl<'a, T> FillGuard<'a, T> {
+    fn new(slice: &'a mut [MaybeUninit<T>]) -> Self {
+        Self {
+            slice,
+            initialized: 0,
+        }
+    }
+
+    /// Initialises the next element of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice has already been filled.
+    fn push(&mut self, elem: T) {
+        self.slice[self.initialized].write(elem);
+        self.initialized += 1;
+    }
+
+    /// Disarms the guard, leaving all the initialised elements in place. This should only be
+    /// called once the whole slice has been filled.
+    fn finish(self) {
+        debug_assert_eq!(self.initialized, self.slice.len());
+        std::mem::forget(self);
+    }
+}
+impl<T> Drop for FillGuard<'_, T> {
+    fn drop(&mut self) {
+        for elem in &mut self.slice[..self.initialized] {
+            // SAFETY: We only ever count elements we've written
+            unsafe { elem.assume_init_drop() };
+        }
+    }
+}
+
+/// Transmutes from `A` to `B`, but at const evaluation time. This is equivalent to
+/// [`std::mem::transmute`] in all other respects, and the same safety contracts must be upheld.
+///
+/// # Safety
+///
+/// See [`std::mem::transmute`], but in general, the bits you provide must be valid in both `A` and
+/// `B`.
+const unsafe fn const_transmute<A, B>(a: A) -> B {
+    if std::mem::size_of::<A>() != std::mem::size_of::<B>() {
+        panic!("size mismatch in const transmute");
+    }
+
+    // This will hold either `A` or `B`, and because their sizes are equal, will have whatever that
+    // size is
+    #[repr(C)]
+    union Union<A, B> {
+        a: ManuallyDrop<A>,
+        b: ManuallyDrop<B>,
+    }
+
+    // We instantiate a manually-droppable version of `a` to make sure we don't drop the data when
+    // we transmute
+    let a = ManuallyDrop::new(a);
+    // Then we put that `a` into our magical union
+    let union = Union::<A, B> { a };
+    // Then we extract it from said union, but as if it were `b`
+    //
+    // SAFETY: `a` and `b` have the same size (guaranteed above), and the caller has guaranteed to
+    // us that the bits are valid in `B`
+    let b_transmute = unsafe { union.b };
+    // And finally we just extract from the `ManuallyDrop` so we have a normal, droppable type
+    // again
+    ManuallyDrop::into_inner(b_transmute)
+}
+
+/// Asserts at compile-time that an [`Array<T, N>`] of the given element type and length has the
+/// given size in bytes (e.g. `assert_array_size!(u32, bs!(1, 0, 1), 20)`). This expands to a
+/// `const` item, so it can be used anywhere an item can, and a mismatch will fail compilation.
+#[macro_export]
+macro_rules! assert_array_size {
+    ($t:ty, $n:ty, $size:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$crate::Array<$t, $n>>() == $size,
+            "array size doesn't match the expected size"
+        );
+    };
+}
+
+/// Types for which a value made of all zero bytes is valid (and is usually their default value),
+/// allowing arrays of them to be created with [`Array::zeroed`].
+///
+/// # Safety
+///
+/// Implementors must be valid when every byte of their memory is zero.
+pub unsafe trait Zeroable {}
+macro_rules! impl_zeroable {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            // SAFETY: Zero is a valid value for all primitive numbers, `false` for `bool`, and
+            // `'\0'` for `char`
+            unsafe impl Zeroable for $ty {}
+        )+
+    };
+}
+impl_zeroable!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char,
+);
+// SAFETY: Arrays of zeroable types are entirely made of zeroable types
+unsafe impl<T: Zeroable, const M: usize> Zeroable for [T; M] {}
+// SAFETY: As above, all the internal types are either zero-sized or made of `T`s
+unsafe impl<T: Zeroable, N: Bitstring> Zeroable for Array<T, N> {}
+// SAFETY: `MaybeUninit` can hold anything at all
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+
+/// The error that occurs when we try to convert from a slice into an [`Array<T, N>`], but the
+/// length is wrong.
+#[derive(Error, Debug)]
+#[error("bad slice length: expected {expected}, found {found}")]
+pub struct BadLength {
+    found: usize,
+    expected: usize,
+}
+
+/// An internal struct that represents the even side of an array. If we have an array of length 6,
+/// then this will divide into two [`ArrayEven`]s whose child types `U` are [`ArrayOdd`]s, and
+/// *their* child types will also be [`ArrayOdd`]s! See [`Array`] for details of how this structure
+/// works practically.
+#[doc(hidden)]
+#[repr(C)]
+pub struct ArrayEven<T, U: sealed::IsArrayImpl> {
+    left: U,
+    right: U,
+    _phantom: ::std::marker::PhantomData<T>,
+}
+
+/// An internal struct that represents the odd side of an array.
+#[doc(hidden)]
+#[repr(C)]
+pub struct ArrayOdd<T, U: sealed::IsArrayImpl> {
+    left: U,
+    right: U,
+    data: T,
+}
+
+/// An internal terminator for arrays. This will only ever appear on [`ArrayOdd`]s, and it reduces
+/// them to be length-1 arrays.
+pub struct ArrayTerm;
+
+mod sealed {
+    /// An internal trait implemented for the array constructors [`super::ArrayEven`],
+    /// [`super::ArrayOdd`], and [`super::ArrayTerm`].
+    pub trait IsArrayImpl {
+        /// The depth of the tree of array constructors, not counting the terminator.
+        const DEPTH: usize;
+    }
+    impl<T, U: IsArrayImpl> IsArrayImpl for super::ArrayEven<T, U> {
+        const DEPTH: usize = U::DEPTH + 1;
+    }
+    impl<T, U: IsArrayImpl> IsArrayImpl for super::ArrayOdd<T, U> {
+        const DEPTH: usize = U::DEPTH + 1;
+    }
+
+    impl IsArrayImpl for super::ArrayTerm {
+        const DEPTH: usize = 0;
+    }
+}
+
+/// Returns the depth of the internal tree representation of [`Array<T, N>`] (which is the same for
+/// every `T`). This should always be the bit length of `N` (see [`crate::BitLength`]), and is
+/// exposed only to allow debugging the array layout.
+#[doc(hidden)]
+pub const fn internal_depth<N: Bitstring>() -> usize {
+    <<N as HasArray>::ArrayType<()> as sealed::IsArrayImpl>::DEPTH
+}
+
+/// A trait implemented for all types with "array types" formed from bitstrings. This is
+/// implemented by default for every [`Bitstring`] (producing a stack-allocated [`Array<T, N>`]).
+pub trait HasArray: Bitstring {
+    /// The internal array type. This is sealed to be one of [`ArrayEven`], [`ArrayOdd`], and
+    /// [`ArrayTerm`].
+    type ArrayType<T>: sealed::IsArrayImpl;
+}
+impl<B: Bitstring> HasArray for B {
+    // If the entire bitstring is `B0`, then return the array terminator, otherwise enter a
+    // recursion (because there's recursion in the underlying conditional there, this has to be its
+    // own recursor)
+    type ArrayType<T> =
+        If<<B::Trimmed as IsB0>::ArrayIsB0, Thunk<ArrayTerm>, ArrayRecurse<T, B::Trimmed>>;
+}
+
+/// An internal recursion helper for producing [`Array<T, N>`].
+pub struct ArrayRecurse<T, B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(T, B)>,
+}
+impl<T, B: Bitstring> Lazy for ArrayRecurse<T, B> {
+    // If the least significant bit is `B0` (i.e. the bitstring represents an even number), go into
+    // the even recursion, otherwise the odd recursion
+    type Output = If<<B::Lsb as IsB0>::ArrayIsB0, ArrayEvenRecurse<T, B>, ArrayOddRecurse<T, B>>;
+}
+
+/// An internal recursion helper for producing [`Array<T, N>`] (even variant).
+pub struct ArrayEvenRecurse<T, B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(T, B)>,
+}
+impl<T, B: Bitstring> Lazy for ArrayEvenRecurse<T, B> {
+    // We're an even bitstring, divide by two (i.e. take the head) and put whatever the array type
+    // of that is inside an `ArrayEven`
+    type Output = ArrayEven<T, <B::Head as HasArray>::ArrayType<T>>;
+}
+
+/// An internal recursion helper for producing [`Array<T, N>`] (odd variant).
+pub struct ArrayOddRecurse<T, B: Bitstring> {
+    _phantom: ::std::marker::PhantomData<(T, B)>,
+}
+impl<T, B: Bitstring> Lazy for ArrayOddRecurse<T, B> {
+    // We're an odd bitstring, divide by two (i.e. take the head) and put whatever the array type
+    // of that is inside an `ArrayOdd` (which will actually contain an instance of `T`)
+    type Output = ArrayOdd<T, <B::Head as HasArray>::ArrayType<T>>;
+}
+
+#[cfg(test)]
+mod drop_safety;
+#[cfg(feature = "array_vec")]
+mod vec;
+
+#[cfg(feature = "array_vec")]
+pub use vec::ArrayVec;
+
+use array_conditionals::{If, Lazy, Thunk};
+use thiserror::Error;
+conditional_system!(pub array_conditionals, super::sealed::IsArrayImpl);
+
+#[test]
+fn arrays() {
+    use crate::bs;
+
+    type Untrimmed = Array<u8, bs!(0, 1, 0)>;
+    type A2 = Array<u8, bs!(1, 0)>;
+    type A3 = Array<u8, bs!(1, 1)>;
+    type A14 = Array<u8, bs!(1, 1, 1, 0)>;
+    type A5Long = Array<u32, bs!(1, 0, 1)>;
+
+    assert_eq!(size_of::<Untrimmed>(), 2);
+    assert_eq!(size_of::<A2>(), 2);
+    assert_eq!(size_of::<A3>(), 3);
+    assert_eq!(size_of::<A14>(), 14);
+    assert_eq!(size_of::<A5Long>(), 4 * 5);
+}
+
+#[test]
+fn arrays_runtime() {
+    use crate::bs;
+
+    type A5Long = Array<u32, bs!(1, 0, 1)>;
+
+    assert!(A5Long::try_from_slice(&[0u32; 5]).is_ok());
+    assert!(A5Long::try_from_slice(&[0u32; 4]).is_err());
+
+    let zeroed = A5Long::default();
+    assert_eq!(zeroed.as_slice(), &[0u32; 5]);
+}
+
+#[test]
+fn arrays_zero_length() {
+    use crate::{B0, bs};
+
+    type Empty = Array<u32, B0>;
+    type EmptyUntrimmed = Array<u32, bs!(0, 0)>;
+
+    assert_eq!(size_of::<Empty>(), 0);
+    assert_eq!(size_of::<EmptyUntrimmed>(), 0);
+    assert_eq!(Empty::len(), 0);
+
+    let mut empty = Empty::default();
+    assert!(empty.as_slice().is_empty());
+    assert!(empty.as_mut_slice().is_empty());
+    assert!(empty.first().is_none());
+    assert!(empty.last().is_none());
+    assert_eq!(empty.iter().count(), 0);
+    assert_eq!((&mut empty).into_iter().count(), 0);
+    assert_eq!(empty.clone().as_slice(), &[] as &[u32]);
+
+    assert!(Empty::try_from_slice(&[]).is_ok());
+    assert!(Empty::try_from_slice(&[0]).is_err());
+    assert!(Empty::new_boxed().as_slice().is_empty());
+}
+
+#[test]
+fn arrays_map_ref() {
+    use crate::bs;
+
+    let strings = Array::<String, bs!(1, 1)>::new_from_slice(&[
+        "a".to_string(),
+        "bcd".to_string(),
+        "ef".to_string(),
+    ]);
+    let lengths: Array<usize, bs!(1, 1)> = strings.map_ref(|s| s.len());
+
+    assert_eq!(lengths.as_slice(), &[1, 3, 2]);
+    // The source is still usable
+    assert_eq!(strings[1], "bcd");
+}
+
+#[test]
+fn arrays_len_const() {
+    use crate::{B0, bs};
+
+    const { assert!(Array::<u8, bs!(1, 0, 1)>::LEN == 5) };
+    const { assert!(Array::<String, B0>::LEN == 0) };
+    let buf = [0u8; Array::<u8, bs!(1, 0, 1)>::LEN];
+    assert_eq!(buf.len(), Array::<u8, bs!(1, 0, 1)>::len());
+}
+
+#[test]
+fn arrays_boxed_trait_objects() {
+    use crate::bs;
+
+    type Callbacks = Array<Box<dyn Fn() -> u32>, bs!(1, 0)>;
+
+    let callbacks = Callbacks::from_fn(|i| {
+        let i = i as u32;
+        Box::new(move || i * 10)
+    });
+    assert_eq!(callbacks[0](), 0);
+    assert_eq!(callbacks[1](), 10);
+    assert_eq!(callbacks.iter().map(|f| f()).sum::<u32>(), 10);
+
+    let results: Vec<_> = callbacks.into_iter().map(|f| f()).collect();
+    assert_eq!(results, [0, 10]);
+}
+
+#[test]
+fn arrays_try_map_ref() {
+    use crate::{B0, bs};
+
+    let strings = Array::<&str, bs!(1, 1)>::new_from_slice(&["1", "23", "4"]);
+    let parsed = strings.try_map_ref(|s| s.parse::<u8>()).unwrap();
+    assert_eq!(parsed.as_slice(), &[1, 23, 4]);
+
+    let strings = Array::<&str, bs!(1, 1)>::new_from_slice(&["1", "x", "y"]);
+    let mut calls = 0;
+    let result = strings.try_map_ref(|s| {
+        calls += 1;
+        s.parse::<u8>()
+    });
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "invalid digit found in string"
+    );
+    // We stopped at the first error, and the source is still usable
+    assert_eq!(calls, 2);
+    assert_eq!(strings[2], "y");
+
+    let empty = Array::<&str, B0>::new();
+    assert!(empty.try_map_ref(|_| Err::<u8, _>(())).is_ok());
+}
+
+#[test]
+fn arrays_const_generic_conversions() {
+    use crate::bs;
+
+    let array = Array::<u16, bs!(1, 1)>::new_from_slice(&[1, 2, 3]);
+    let converted: [u16; 3] = array.clone().try_into().unwrap();
+    assert_eq!(converted, [1, 2, 3]);
+    assert!(<[u16; 4]>::try_from(array).is_err());
+
+    let back: Array<u16, bs!(1, 1)> = [4, 5, 6].try_into().unwrap();
+    assert_eq!(back.as_slice(), &[4, 5, 6]);
+    assert!(Array::<u16, bs!(1, 1)>::try_from([4, 5]).is_err());
+}
+
+#[test]
+fn arrays_disjoint_mut() {
+    use crate::bs;
+
+    let mut array = Array::<u8, bs!(1, 0, 0)>::new_from_slice(&[1, 2, 3, 4]);
+
+    let [a, b] = array.get_disjoint_mut([3, 0]).unwrap();
+    std::mem::swap(a, b);
+    assert_eq!(array.as_slice(), &[4, 2, 3, 1]);
+
+    assert!(array.get_disjoint_mut([1, 2, 1]).is_none());
+    assert!(array.get_disjoint_mut([0, 4]).is_none());
+}
+
+#[test]
+fn arrays_from_tuples() {
+    use crate::bs;
+
+    let pair = Array::<&str, bs!(1, 0)>::from(("a", "b"));
+    let triple: Array<u8, bs!(1, 1)> = (1, 2, 3).into();
+    let octet = Array::<u8, bs!(1, 0, 0, 0)>::from((1, 2, 3, 4, 5, 6, 7, 8));
+
+    assert_eq!(pair.as_slice(), &["a", "b"]);
+    assert_eq!(triple.as_slice(), &[1, 2, 3]);
+    assert_eq!(octet.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn arrays_from_array_iter() {
+    use crate::{B0, bs};
+
+    let array = Array::<String, bs!(1, 1)>::from_array_iter(["a", "b", "c"].map(String::from));
+    assert_eq!(array.as_slice(), ["a", "b", "c"]);
+    let array: Array<u8, bs!(1, 0, 1)> = Array::from_array_iter([1, 2, 3, 4, 5]);
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 5]);
+    assert!(Array::<u8, B0>::from_array_iter([]).as_slice().is_empty());
+}
+
+#[test]
+fn arrays_from_slices() {
+    use crate::bs;
+
+    type A5 = Array<u8, bs!(1, 0, 1)>;
+
+    let two = A5::try_new_from_slices(&[&[1, 2], &[3, 4, 5]]).unwrap();
+    assert_eq!(two.as_slice(), &[1, 2, 3, 4, 5]);
+    let three = A5::new_from_slices(&[&[1], &[], &[2, 3, 4, 5]]);
+    assert_eq!(three.as_slice(), &[1, 2, 3, 4, 5]);
+
+    assert!(A5::try_new_from_slices(&[&[1, 2], &[3, 4]]).is_err());
+    assert!(A5::try_new_from_slices(&[&[1, 2, 3], &[4, 5, 6]]).is_err());
+}
+
+#[test]
+fn arrays_fold() {
+    use crate::bs;
+
+    let array = Array::<u32, bs!(1, 0, 0)>::new_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(array.fold(0, |acc, x| acc + x), 10);
+
+    let mut visited = 0;
+    let result = array.try_fold(0, |acc, &x| {
+        visited += 1;
+        if x == 2 {
+            Err("found two")
+        } else {
+            Ok(acc + x)
+        }
+    });
+    assert_eq!(result, Err("found two"));
+    assert_eq!(visited, 2);
+    assert_eq!(array.try_fold(0, |acc, &x| Ok::<_, ()>(acc + x)), Ok(10));
+}
+
+#[test]
+fn arrays_depth() {
+    use crate::{B0, B1, BitLength, bs};
+
+    macro_rules! assert_depth {
+        ($($n:ty => $depth:literal),+) => {
+            $(
+                assert_eq!(internal_depth::<$n>(), $depth);
+                assert_eq!(internal_depth::<$n>(), BitLength::<$n>::UNSIGNED);
+            )+
+        };
+    }
+    assert_depth!(
+        B0 => 0,
+        B1 => 1,
+        bs!(1, 1) => 2,
+        bs!(0, 1, 1, 0) => 3,
+        bs!(1, 1, 1, 1) => 4,
+        bs!(1, 0, 0, 0, 0, 0, 0, 0) => 8,
+        bs!(1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1) => 16
+    );
+}
+
+#[test]
+fn arrays_as_const_generic() {
+    use crate::bs;
+
+    fn sum(array: &[u8; 3]) -> u8 {
+        array.iter().sum()
+    }
+
+    let mut array = Array::<u8, bs!(1, 1)>::new_from_slice(&[1, 2, 3]);
+    assert_eq!(sum(array.as_array_ref()), 6);
+
+    let borrowed: &mut [u8; 3] = array.as_array_mut();
+    borrowed[0] = 10;
+    assert_eq!(array.as_slice(), &[10, 2, 3]);
+}
+
+#[test]
+fn arrays_chunks() {
+    use crate::{B1, bs};
+
+    let mut array = Array::<u8, bs!(1, 1, 1)>::new_from_slice(&[1, 2, 3, 4, 5, 6, 7]);
+
+    let chunks = array.chunks_array::<bs!(1, 0)>();
+    assert_eq!(Array::<Array<u8, bs!(1, 0)>, bs!(1, 1)>::len(), 3);
+    assert_eq!(chunks[0].as_slice(), &[1, 2]);
+    assert_eq!(chunks[2].as_slice(), &[5, 6]);
+    assert_eq!(array.chunks_remainder::<bs!(1, 0)>(), &[7]);
+
+    for chunk in array.chunks_array_mut::<bs!(1, 0)>().iter_mut() {
+        chunk.as_mut_slice().swap(0, 1);
+    }
+    array.chunks_remainder_mut::<bs!(1, 0)>()[0] = 0;
+    assert_eq!(array.as_slice(), &[2, 1, 4, 3, 6, 5, 0]);
+
+    // Evenly dividing chunks have no remainder
+    assert!(array.chunks_remainder::<B1>().is_empty());
+    assert_eq!(
+        array.chunks_array::<bs!(1, 1, 1)>()[0].as_slice(),
+        array.as_slice()
+    );
+    // Chunks longer than the array give no chunks
+    assert!(
+        array
+            .chunks_array::<bs!(1, 0, 0, 0)>()
+            .as_slice()
+            .is_empty()
+    );
+    assert_eq!(array.chunks_remainder::<bs!(1, 0, 0, 0)>().len(), 7);
+}
+
+#[test]
+fn arrays_windows() {
+    use crate::bs;
+
+    let array = Array::<u8, bs!(1, 0, 0)>::new_from_slice(&[1, 2, 3, 4]);
+    let windows: Vec<_> = array
+        .windows_array::<bs!(1, 0)>()
+        .map(|window| *window.as_array_ref::<2>())
+        .collect();
+    assert_eq!(windows, [[1, 2], [2, 3], [3, 4]]);
+
+    assert_eq!(array.windows_array::<bs!(1, 0, 0)>().count(), 1);
+    assert_eq!(array.windows_array::<bs!(1, 0, 1)>().count(), 0);
+}
+
+#[test]
+fn arrays_windows_reduce() {
+    use crate::bs;
+
+    let array = Array::<u32, bs!(1, 0, 1)>::new_from_slice(&[1, 2, 3, 4, 5]);
+    assert_eq!(
+        array.windows_reduce(3, |w| w.iter().sum::<u32>()),
+        [6, 9, 12]
+    );
+    assert_eq!(array.windows_reduce(5, <[u32]>::len), [5]);
+    assert_eq!(array.windows_reduce(1, |w| w[0] * 2), [2, 4, 6, 8, 10]);
+    assert!(array.windows_reduce(6, |w| w.len()).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn arrays_windows_reduce_zero() {
+    use crate::bs;
+
+    Array::<u32, bs!(1, 0, 1)>::new().windows_reduce(0, |w| w.len());
+}
+
+#[test]
+fn arrays_boxed() {
+    use crate::bs;
+
+    let array = Array::<String, bs!(1, 1)>::from_fn(|i| i.to_string());
+    let boxed = array.clone().boxed();
+    assert_eq!(*boxed, array);
+    assert_eq!(boxed.as_slice(), ["0", "1", "2"]);
+}
+
+#[test]
+fn arrays_search() {
+    use crate::{B0, bs};
+
+    let array = Array::<u32, bs!(1, 1, 0)>::new_from_slice(&[3, 8, 5, 8, 1, 6]);
+    assert_eq!(array.position(|&x| x == 8), Some(1));
+    assert_eq!(array.rposition(|&x| x == 8), Some(3));
+    assert_eq!(array.position(|&x| x > 10), None);
+    assert_eq!(array.rposition(|&x| x > 10), None);
+    assert_eq!(array.find(|&&x| x % 2 == 0), Some(&8));
+    assert_eq!(array.find(|&&x| x == 0), None);
+
+    assert!(array.any(|&x| x == 1));
+    assert!(!array.any(|&x| x == 2));
+    assert!(array.all(|&x| x < 10));
+    assert!(!array.all(|&x| x % 2 == 0));
+
+    let empty = Array::<u32, B0>::new();
+    assert!(!empty.any(|_| true));
+    assert!(empty.all(|_| false));
+}
+
+#[test]
+fn arrays_assert_size() {
+    use crate::bs;
+
+    crate::assert_array_size!(u32, bs!(1, 0, 1), 20);
+    crate::assert_array_size!(u8, bs!(0, 1, 1, 1, 0), 14);
+    crate::assert_array_size!(u64, crate::B0, 0);
+}
+
+#[test]
+fn arrays_try_chunks() {
+    use crate::bs;
+
+    let array = Array::<u8, bs!(1, 1, 0)>::new_from_slice(&[1, 2, 3, 4, 5, 6]);
+    let chunks: Vec<_> = array.try_chunks(3).unwrap().collect();
+    assert_eq!(chunks, [&[1, 2, 3], &[4, 5, 6]]);
+    assert_eq!(array.try_chunks(6).unwrap().count(), 1);
+
+    let err = array.try_chunks(4).unwrap_err();
+    assert_eq!(err.to_string(), "bad slice length: expected 4, found 6");
+    let err = array.try_chunks(5).unwrap_err();
+    assert_eq!(err.to_string(), "bad slice length: expected 5, found 6");
+    let err = array.try_chunks(7).unwrap_err();
+    assert_eq!(err.to_string(), "bad slice length: expected 7, found 6");
+}
+
+#[test]
+fn arrays_borrow() {
+    use crate::bs;
+    use std::collections::HashMap;
+
+    type A3 = Array<u8, bs!(1, 1)>;
+
+    let mut map = HashMap::new();
+    map.insert(A3::new_from_slice(&[1, 2, 3]), "a");
+    map.insert(A3::new_from_slice(&[4, 5, 6]), "b");
+    assert_eq!(map.get(&[4, 5, 6][..]), Some(&"b"));
+    assert_eq!(map.get(&[1, 2][..]), None);
+
+    let mut array = A3::new_from_slice(&[1, 2, 3]);
+    let slice: &mut [u8] = array.borrow_mut();
+    slice.reverse();
+    assert_eq!(array.as_slice(), &[3, 2, 1]);
+    assert_eq!(<A3 as Borrow<[u8]>>::borrow(&array), &[3, 2, 1]);
+}
+
+#[test]
+fn arrays_split_head() {
+    use crate::{B0, bs};
+
+    let array =
+        Array::<String, bs!(1, 0, 1)>::new_from_slice(&["a", "b", "c", "d", "e"].map(String::from));
+    let (head, tail) = array.split_head::<bs!(1, 0)>();
+    assert_eq!(Array::<String, bs!(1, 0)>::len(), 2);
+    assert_eq!(head.as_slice(), ["a", "b"]);
+    assert_eq!(tail.as_slice(), ["c", "d", "e"]);
+
+    let (head, tail) = tail.split_head::<B0>();
+    assert!(head.as_slice().is_empty());
+    assert_eq!(tail.as_slice(), ["c", "d", "e"]);
+    let (head, tail) = tail.split_head::<bs!(1, 1)>();
+    assert_eq!(head.as_slice(), ["c", "d", "e"]);
+    assert!(tail.as_slice().is_empty());
+}
+
+#[test]
+fn arrays_rotate_const() {
+    use crate::{B0, B1, bs};
+
+    type N = bs!(1, 0, 1);
+    let array = Array::<u8, N>::new_from_slice(&[1, 2, 3, 4, 5]);
+
+    assert_eq!(
+        array.clone().rotate_const::<B0>().as_slice(),
+        &[1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        array.clone().rotate_const::<B1>().as_slice(),
+        &[2, 3, 4, 5, 1]
+    );
+    assert_eq!(
+        array.clone().rotate_const::<bs!(1, 0)>().as_slice(),
+        &[3, 4, 5, 1, 2]
+    );
+    // Amounts past the length wrap around, just like the runtime rotation
+    let mut expected = array.clone();
+    expected.as_mut_slice().rotate_left(7 % 5);
+    assert_eq!(array.clone().rotate_const::<bs!(1, 1, 1)>(), expected);
+    expected.as_mut_slice().rotate_left(1);
+    assert_eq!(array.clone().rotate_const::<bs!(1, 1)>(), expected);
+
+    // Rotating by any multiple of the length is the identity
+    assert_eq!(array.clone().rotate_const::<N>(), array);
+    assert_eq!(array.clone().rotate_const::<bs!(1, 1, 1, 1)>(), array);
+    let empty = Array::<u8, B0>::new();
+    assert_eq!(empty.rotate_const::<bs!(1, 1)>(), Array::<u8, B0>::new());
+}
+
+#[test]
+fn arrays_interleave() {
+    use crate::{B0, bs};
+
+    let array = Array::<String, bs!(1, 1, 0)>::new_from_slice(
+        &["l0", "r0", "l1", "r1", "l2", "r2"].map(String::from),
+    );
+    let (left, right) = array.deinterleave();
+    assert_eq!(Array::<String, Shr<bs!(1, 1, 0), B1>>::len(), 3);
+    assert_eq!(left.as_slice(), ["l0", "l1", "l2"]);
+    assert_eq!(right.as_slice(), ["r0", "r1", "r2"]);
+
+    let interleaved = left.interleave(right);
+    assert_eq!(interleaved.as_slice(), ["l0", "r0", "l1", "r1", "l2", "r2"]);
+
+    let (left, right) = Array::<u8, B0>::new().deinterleave();
+    assert!(left.interleave(right).as_slice().is_empty());
+}
+
+#[test]
+fn arrays_resize() {
+    use crate::bs;
+
+    let array = Array::<u8, bs!(1, 1)>::new_from_slice(&[1, 2, 3]);
+    let grown = array.resize::<bs!(1, 0, 1)>();
+    assert_eq!(grown.as_slice(), &[1, 2, 3, 0, 0]);
+    let shrunk = grown.resize::<bs!(1, 0)>();
+    assert_eq!(shrunk.as_slice(), &[1, 2]);
+    let same = shrunk.resize::<bs!(0, 1, 0)>();
+    assert_eq!(same.as_slice(), &[1, 2]);
+    assert!(same.resize::<crate::B0>().as_slice().is_empty());
+}
+
+#[test]
+fn arrays_into_boxed_slice() {
+    use crate::{B0, bs};
+
+    let array = Array::<u16, bs!(1, 0, 1)>::new_boxed_from_slice(&[1, 2, 3, 4, 5]);
+    let slice = array.into_boxed_slice();
+    assert_eq!(slice.len(), 5);
+    assert_eq!(&*slice, &[1, 2, 3, 4, 5]);
+
+    let slice: Box<[u16]> = Array::<u16, bs!(1, 1)>::new_boxed().into();
+    assert_eq!(&*slice, &[0, 0, 0]);
+
+    let empty = Array::<u64, B0>::new_boxed().into_boxed_slice();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn arrays_debug() {
+    use crate::{B0, bs};
+
+    let array = Array::<u8, bs!(1, 1)>::new_from_slice(&[1, 2, 3]);
+    assert_eq!(format!("{array:?}"), "[1, 2, 3]");
+    assert_eq!(format!("{:?}", Array::<u8, B0>::new()), "[]");
+
+    let nested = Array::<Array<u8, bs!(1, 0)>, bs!(1, 0)>::new_from_slice(&[
+        Array::new_from_slice(&[1, 2]),
+        Array::new_from_slice(&[3, 4]),
+    ]);
+    assert_eq!(format!("{nested:?}"), "[[1, 2], [3, 4]]");
+    assert_eq!(
+        format!("{nested:#?}"),
+        "[\n    [\n        1,\n        2,\n    ],\n    [\n        3,\n        4,\n    ],\n]"
+    );
+    // Chunked views print the same way
+    let flat = Array::<u8, bs!(1, 0, 0)>::new_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(
+        format!("{:?}", flat.chunks_array::<bs!(1, 0)>()),
+        "[[1, 2], [3, 4]]"
+    );
+}
+
+#[test]
+fn arrays_reverse_iter() {
+    use crate::bs;
+
+    let mut array = Array::<u8, bs!(1, 0, 0)>::new_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(
+        array.iter().rev().copied().collect::<Vec<_>>(),
+        [4, 3, 2, 1]
+    );
+    for (elem, i) in array.iter_mut().rev().zip(0..) {
+        *elem += i;
+    }
+    assert_eq!(array.as_slice(), &[4, 4, 4, 4]);
+
+    let array = Array::<String, bs!(1, 1)>::new_from_slice(&["a", "b", "c"].map(String::from));
+    assert_eq!(
+        array.clone().into_iter_rev().collect::<Vec<_>>(),
+        ["c", "b", "a"]
+    );
+    let mut iter = array.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().as_deref(), Some("c"));
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.next_back().as_deref(), Some("b"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn arrays_copy_from_slice() {
+    use crate::bs;
+
+    let mut array = Array::<u32, bs!(1, 0, 0)>::new();
+    array.copy_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4]);
+    array.copy_from_slice(&[u32::MAX; 4]);
+    assert_eq!(array.as_slice(), &[u32::MAX; 4]);
+}
+
+#[test]
+fn arrays_copy_within() {
+    use crate::bs;
+
+    let mut array = Array::<u8, bs!(1, 1, 0)>::new_from_slice(&[1, 2, 3, 4, 5, 6]);
+    // Forward, overlapping
+    array.copy_within(0..4, 2);
+    assert_eq!(array.as_slice(), &[1, 2, 1, 2, 3, 4]);
+    // Backward, overlapping
+    array.copy_within(2.., 0);
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 3, 4]);
+    array.copy_within(..0, 6);
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn arrays_copy_within_out_of_bounds() {
+    use crate::bs;
+
+    let mut array = Array::<u8, bs!(1, 0, 0)>::new();
+    array.copy_within(1..3, 3);
+}
+
+#[test]
+fn arrays_index_ranges() {
+    use crate::bs;
+
+    let mut array = Array::<u8, bs!(1, 0, 1)>::new_from_slice(&[1, 2, 3, 4, 5]);
+    assert_eq!(array[2], 3);
+    assert_eq!(&array[1..3], &[2, 3]);
+    assert_eq!(&array[..2], &[1, 2]);
+    assert_eq!(&array[3..], &[4, 5]);
+    assert_eq!(&array[..], &[1, 2, 3, 4, 5]);
+    assert_eq!(&array[1..=3], &[2, 3, 4]);
+    assert_eq!(&array[..=0], &[1]);
+    assert!(array[5..].is_empty());
+
+    array[1..3].copy_from_slice(&[20, 30]);
+    array[..=0][0] = 10;
+    array[4..].fill(50);
+    array[3] = 40;
+    assert_eq!(array.as_slice(), &[10, 20, 30, 40, 50]);
+}
+
+#[test]
+#[should_panic]
+fn arrays_index_range_out_of_bounds() {
+    use crate::bs;
+
+    let array = Array::<u8, bs!(1, 0, 1)>::new();
+    let _ = &array[3..6];
+}
+
+#[test]
+#[should_panic]
+fn arrays_index_range_inclusive_out_of_bounds() {
+    use crate::bs;
+
+    let mut array = Array::<u8, bs!(1, 0, 1)>::new();
+    array[..=5].fill(1);
+}
+
+#[test]
+fn arrays_as_flattened() {
+    use crate::{B0, bs};
+
+    type Row = Array<u32, bs!(1, 1)>;
+    let mut nested = Array::<Row, bs!(1, 0)>::from_fn(|r| Row::from_fn(|c| (r * 10 + c) as u32));
+    let manual: Vec<_> = nested.iter().flat_map(|row| row.iter().copied()).collect();
+    assert_eq!(nested.as_flattened(), manual);
+    assert_eq!(nested.as_flattened(), &[0, 1, 2, 10, 11, 12]);
+
+    nested.as_flattened_mut()[4] = 0;
+    assert_eq!(nested[1].as_slice(), &[10, 0, 12]);
+
+    assert!(Array::<Row, B0>::new().as_flattened().is_empty());
+    assert!(
+        Array::<Array<u32, B0>, bs!(1, 1)>::new()
+            .as_flattened_mut()
+            .is_empty()
+    );
+}
+
+#[test]
+fn arrays_retain_prefix() {
+    use crate::bs;
+
+    type A6 = Array<u32, bs!(1, 1, 0)>;
+
+    let mut array = A6::new_from_slice(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(array.retain_prefix(|x| x % 2 == 0), 3);
+    // The tail is untouched
+    assert_eq!(array.as_slice(), &[2, 4, 6, 4, 5, 6]);
+
+    let mut array = A6::new_from_slice(&[7, 1, 8, 2, 9, 3]);
+    assert_eq!(array.retain_prefix(|&x| x > 5), 3);
+    assert_eq!(&array.as_slice()[..3], &[7, 8, 9]);
+
+    let mut array = A6::new_from_slice(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(array.retain_prefix(|_| true), 6);
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(array.retain_prefix(|_| false), 0);
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+#[should_panic]
+fn arrays_copy_from_slice_bad_length() {
+    use crate::bs;
+
+    let mut array = Array::<u32, bs!(1, 0, 0)>::new();
+    array.copy_from_slice(&[1, 2, 3]);
+}
+
+#[test]
+fn arrays_swap_with_slice() {
+    use crate::bs;
+
+    let mut array =
+        Array::<String, bs!(1, 1)>::new_from_slice(&["a".into(), "b".into(), "c".into()]);
+    let mut scratch = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    array.swap_with_slice(&mut scratch);
+    assert_eq!(array.as_slice(), ["x", "y", "z"]);
+    assert_eq!(scratch, ["a", "b", "c"]);
+
+    array.swap_with_slice(&mut scratch);
+    assert_eq!(array.as_slice(), ["a", "b", "c"]);
+}
+
+#[test]
+fn arrays_fill_from_iter() {
+    use crate::{B0, bs};
+
+    let mut array = Array::<u8, bs!(1, 0, 0)>::new_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(array.fill_from_iter([9, 8]), 2);
+    assert_eq!(array.as_slice(), &[9, 8, 3, 4]);
+    assert_eq!(array.fill_from_iter(5..9), 4);
+    assert_eq!(array.as_slice(), &[5, 6, 7, 8]);
+    // Only as many items as fit are taken
+    let mut iter = 10..;
+    assert_eq!(array.fill_from_iter(&mut iter), 4);
+    assert_eq!(array.as_slice(), &[10, 11, 12, 13]);
+    assert_eq!(iter.next(), Some(14));
+
+    let mut empty = Array::<u8, B0>::new();
+    assert_eq!(empty.fill_from_iter([1, 2, 3]), 0);
+}
+
+#[test]
+#[should_panic]
+fn arrays_swap_with_slice_bad_length() {
+    use crate::bs;
+
+    let mut array = Array::<u32, bs!(1, 0, 0)>::new();
+    array.swap_with_slice(&mut [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn arrays_chunk() {
+    use crate::bs;
+
+    let mut array = Array::<u8, bs!(1, 1, 0)>::new_from_slice(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(array.chunk::<bs!(1, 0)>(0).unwrap().as_slice(), &[1, 2]);
+    assert_eq!(array.chunk::<bs!(1, 0)>(2).unwrap().as_slice(), &[5, 6]);
+    assert!(array.chunk::<bs!(1, 0)>(3).is_none());
+    // Chunks that don't evenly divide the array aren't allowed
+    assert!(array.chunk::<bs!(1, 0, 0)>(0).is_none());
+
+    array
+        .chunk_mut::<bs!(1, 1)>(1)
+        .unwrap()
+        .as_mut_slice()
+        .fill(0);
+    assert_eq!(array.as_slice(), &[1, 2, 3, 0, 0, 0]);
+    assert!(array.chunk_mut::<bs!(1, 1)>(2).is_none());
+}
+
+#[test]
+fn arrays_swap_remove() {
+    use crate::{B0, B1, bs};
+
+    let array =
+        Array::<String, bs!(1, 0, 1)>::new_from_slice(&["a", "b", "c", "d", "e"].map(String::from));
+    let (elem, rest) = array.swap_remove::<B1>();
+    assert_eq!(elem, "b");
+    assert_eq!(rest.as_slice(), ["a", "e", "c", "d"]);
+    let (elem, rest) = rest.swap_remove::<bs!(1, 1)>();
+    assert_eq!(elem, "d");
+    assert_eq!(rest.as_slice(), ["a", "e", "c"]);
+    let (elem, rest) = rest.swap_remove::<B0>();
+    assert_eq!(elem, "a");
+    assert_eq!(rest.as_slice(), ["c", "e"]);
+}
+
+#[test]
+fn arrays_from_fn() {
+    use crate::bs;
+
+    let array = Array::<usize, bs!(1, 0, 1)>::from_fn(|i| i * i);
+    assert_eq!(array.as_slice(), &[0, 1, 4, 9, 16]);
+
+    // 2^16 `u64`s is half a megabyte, which is better off on the heap
+    type Large = bs!(1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+    let boxed = Array::<u64, Large>::from_fn_boxed(|i| i as u64 * 3);
+    assert_eq!(boxed.as_slice().len(), 1 << 16);
+    assert!(boxed.iter().enumerate().all(|(i, &x)| x == i as u64 * 3));
+}
+
+#[test]
+fn arrays_try_from_fn() {
+    use crate::{B0, bs};
+
+    type A5 = Array<u8, bs!(1, 0, 1)>;
+
+    let array = A5::try_from_fn(|i| u8::try_from(i * 10)).unwrap();
+    assert_eq!(array.as_slice(), &[0, 10, 20, 30, 40]);
+    assert_eq!(A5::try_from_fn(Err::<u8, _>).unwrap_err(), 0);
+    assert_eq!(
+        A5::try_from_fn(|i| "1,2,x,4,y".split(',').nth(i).unwrap().parse::<u8>())
+            .unwrap_err()
+            .to_string(),
+        "invalid digit found in string"
+    );
+
+    let mut calls = 0;
+    let empty = Array::<u8, B0>::try_from_fn(|_| {
+        calls += 1;
+        Err(())
+    });
+    assert!(empty.unwrap().as_slice().is_empty());
+    assert_eq!(calls, 0);
+}
+
+#[test]
+fn arrays_ptr() {
+    use crate::{B0, bs};
+
+    let mut array = Array::<u32, bs!(1, 0, 1)>::new_from_slice(&[1, 2, 3, 4, 5]);
+    let ptr = array.as_ptr();
+    assert_eq!(ptr, array.as_slice().as_ptr());
+    // SAFETY: The pointer is valid for all five elements
+    let read = unsafe { std::slice::from_raw_parts(ptr, 5) };
+    assert_eq!(read, array.as_slice());
+
+    let ptr = array.as_mut_ptr();
+    // SAFETY: As above
+    unsafe { ptr.add(4).write(0) };
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 0]);
+
+    // Zero-length arrays still give aligned pointers
+    let mut empty = Array::<u64, B0>::new();
+    assert!(empty.as_ptr().is_aligned());
+    assert!(empty.as_mut_ptr().is_aligned());
+}
+
+#[test]
+fn arrays_for_each_mut() {
+    use crate::bs;
+
+    let mut array = Array::<usize, bs!(1, 0, 1)>::new_from_slice(&[10, 20, 30, 40, 50]);
+    let mut visited = Vec::new();
+    array.for_each_mut(|i, elem| {
+        visited.push(i);
+        *elem += i;
+    });
+    assert_eq!(visited, [0, 1, 2, 3, 4]);
+    assert_eq!(array.as_slice(), &[10, 21, 32, 43, 54]);
+}
+
+#[test]
+fn arrays_lengths() {
+    use crate::bs;
+
+    // Checks that the length and size of an array of each given length are exactly right, at
+    // compile-time. This is exhaustive over small lengths, to catch any off-by-one errors in how
+    // the internal representation splits lengths into halves.
+    macro_rules! check_lengths {
+        ($($len:literal => ($($bit:tt),+)),+ $(,)?) => {
+            $(
+                const { assert!(Array::<u8, bs!($($bit),+)>::len() == $len) };
+                const { assert!(size_of::<Array<u8, bs!($($bit),+)>>() == $len) };
+                const { assert!(internal_depth::<bs!($($bit),+)>() == [$($bit),+].len()) };
+            )+
+        };
+    }
+
+    check_lengths! {
+        1 => (1),
+        2 => (1, 0),
+        3 => (1, 1),
+        4 => (1, 0, 0),
+        5 => (1, 0, 1),
+        6 => (1, 1, 0),
+        7 => (1, 1, 1),
+        8 => (1, 0, 0, 0),
+        9 => (1, 0, 0, 1),
+        10 => (1, 0, 1, 0),
+        11 => (1, 0, 1, 1),
+        12 => (1, 1, 0, 0),
+        13 => (1, 1, 0, 1),
+        14 => (1, 1, 1, 0),
+        15 => (1, 1, 1, 1),
+        16 => (1, 0, 0, 0, 0),
+        17 => (1, 0, 0, 0, 1),
+        18 => (1, 0, 0, 1, 0),
+        19 => (1, 0, 0, 1, 1),
+        20 => (1, 0, 1, 0, 0),
+        21 => (1, 0, 1, 0, 1),
+        22 => (1, 0, 1, 1, 0),
+        23 => (1, 0, 1, 1, 1),
+        24 => (1, 1, 0, 0, 0),
+        25 => (1, 1, 0, 0, 1),
+        26 => (1, 1, 0, 1, 0),
+        27 => (1, 1, 0, 1, 1),
+        28 => (1, 1, 1, 0, 0),
+        29 => (1, 1, 1, 0, 1),
+        30 => (1, 1, 1, 1, 0),
+        31 => (1, 1, 1, 1, 1),
+        32 => (1, 0, 0, 0, 0, 0),
+        33 => (1, 0, 0, 0, 0, 1),
+        34 => (1, 0, 0, 0, 1, 0),
+        35 => (1, 0, 0, 0, 1, 1),
+        36 => (1, 0, 0, 1, 0, 0),
+        37 => (1, 0, 0, 1, 0, 1),
+        38 => (1, 0, 0, 1, 1, 0),
+        39 => (1, 0, 0, 1, 1, 1),
+        40 => (1, 0, 1, 0, 0, 0),
+        41 => (1, 0, 1, 0, 0, 1),
+        42 => (1, 0, 1, 0, 1, 0),
+        43 => (1, 0, 1, 0, 1, 1),
+        44 => (1, 0, 1, 1, 0, 0),
+        45 => (1, 0, 1, 1, 0, 1),
+        46 => (1, 0, 1, 1, 1, 0),
+        47 => (1, 0, 1, 1, 1, 1),
+        48 => (1, 1, 0, 0, 0, 0),
+        49 => (1, 1, 0, 0, 0, 1),
+        50 => (1, 1, 0, 0, 1, 0),
+        51 => (1, 1, 0, 0, 1, 1),
+        52 => (1, 1, 0, 1, 0, 0),
+        53 => (1, 1, 0, 1, 0, 1),
+        54 => (1, 1, 0, 1, 1, 0),
+        55 => (1, 1, 0, 1, 1, 1),
+        56 => (1, 1, 1, 0, 0, 0),
+        57 => (1, 1, 1, 0, 0, 1),
+        58 => (1, 1, 1, 0, 1, 0),
+        59 => (1, 1, 1, 0, 1, 1),
+        60 => (1, 1, 1, 1, 0, 0),
+        61 => (1, 1, 1, 1, 0, 1),
+        62 => (1, 1, 1, 1, 1, 0),
+        63 => (1, 1, 1, 1, 1, 1),
+        64 => (1, 0, 0, 0, 0, 0, 0),
+    }
+}
+
+#[test]
+fn arrays_map_while() {
+    use crate::bs;
+
+    let array = Array::<i32, bs!(1, 0, 1)>::new_from_slice(&[4, 3, -1, 2, 1]);
+    assert_eq!(array.map_while(|&x| u32::try_from(x).ok()), [4, 3]);
+    assert_eq!(array.map_while(|&x| Some(x * 2)), [8, 6, -2, 4, 2]);
+    assert!(array.map_while(|_| None::<i32>).is_empty());
+}
+
+#[test]
+fn arrays_map_collect() {
+    use crate::bs;
+    use std::collections::HashSet;
+
+    let array = Array::<&str, bs!(1, 0, 0)>::new_from_slice(&["a", "bcd", "ef", "ghi"]);
+    let lens: Vec<usize> = array.map_collect(|s| s.len());
+    assert_eq!(lens, [1, 3, 2, 3]);
+    let unique: HashSet<usize> = array.map_collect(|s| s.len());
+    assert_eq!(unique, HashSet::from([1, 2, 3]));
+    let joined: String = array.map_collect(|s| s.to_uppercase());
+    assert_eq!(joined, "ABCDEFGHI");
+}
+
+#[test]
+fn arrays_zeroed() {
+    use crate::bs;
+
+    type A5 = Array<u32, bs!(1, 0, 1)>;
+    type A1000 = Array<u32, bs!(1, 1, 1, 1, 1, 0, 1, 0, 0, 0)>;
+
+    assert_eq!(A5::zeroed(), A5::new());
+    assert_eq!(A5::zeroed().as_slice(), &[0; 5]);
+    assert_eq!(*A1000::zeroed_boxed(), *A1000::new_boxed());
+    assert!(Array::<u32, crate::B0>::zeroed().as_slice().is_empty());
+
+    let floats = Array::<f64, bs!(1, 1)>::zeroed();
+    assert_eq!(floats.as_slice(), &[0.0; 3]);
+    let nested = Array::<[bool; 2], bs!(1, 0)>::zeroed();
+    assert_eq!(nested.as_slice(), &[[false; 2]; 2]);
+}
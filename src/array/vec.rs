@@ -0,0 +1,172 @@
+use super::Array;
+use crate::Bitstring;
+use std::{fmt, mem::MaybeUninit};
+
+/// A growable buffer with a fixed capacity of `Cap::UNSIGNED` elements, stored inline in an
+/// [`Array`] rather than on the heap. This keeps track of how many elements are initialised at
+/// runtime, so elements can be pushed and popped freely up to the capacity.
+///
+/// Pushing onto a full [`ArrayVec<T, Cap>`] doesn't panic, it just gives the element back:
+///
+/// ```
+/// # use typebits::{ArrayVec, bs};
+/// let mut vec = ArrayVec::<u8, bs!(1, 0)>::new();
+/// assert_eq!(vec.push(1), Ok(()));
+/// assert_eq!(vec.push(2), Ok(()));
+/// assert_eq!(vec.push(3), Err(3));
+/// assert_eq!(vec.as_slice(), &[1, 2]);
+/// ```
+pub struct ArrayVec<T, Cap: Bitstring> {
+    data: Array<MaybeUninit<T>, Cap>,
+    /// The number of elements at the start of `data` that have been initialised.
+    len: usize,
+}
+impl<T, Cap: Bitstring> ArrayVec<T, Cap> {
+    /// Creates a new, empty [`ArrayVec<T, Cap>`].
+    pub const fn new() -> Self {
+        Self {
+            data: Array::uninit(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in this [`ArrayVec<T, Cap>`].
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns whether or not this [`ArrayVec<T, Cap>`] has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Returns whether or not this [`ArrayVec<T, Cap>`] has as many elements as it can hold.
+    pub const fn is_full(&self) -> bool {
+        self.len == Cap::UNSIGNED
+    }
+    /// Returns the maximum number of elements this [`ArrayVec<T, Cap>`] can hold, which is equal
+    /// to [`Cap::UNSIGNED`].
+    pub const fn capacity() -> usize {
+        Cap::UNSIGNED
+    }
+
+    /// Appends the given element to the end of this [`ArrayVec<T, Cap>`]. If it's already full,
+    /// this will return the element back as an error.
+    pub fn push(&mut self, elem: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(elem);
+        }
+
+        self.data[self.len].write(elem);
+        self.len += 1;
+        Ok(())
+    }
+    /// Removes the last element from this [`ArrayVec<T, Cap>`] and returns it, or `None` if it's
+    /// empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: Everything below the old length was initialised, and we've just made sure this
+        // one won't be read again
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+    /// Removes every element from this [`ArrayVec<T, Cap>`], dropping them.
+    pub fn clear(&mut self) {
+        // Set the length first, so a panicking destructor can't lead to a double drop
+        let len = self.len;
+        self.len = 0;
+        // SAFETY: These were all initialised, and we've already forgotten them
+        unsafe {
+            std::ptr::drop_in_place(&mut self.data.as_mut_slice()[..len] as *mut _ as *mut [T])
+        }
+    }
+
+    /// Gets a slice of the elements in this [`ArrayVec<T, Cap>`].
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `len` elements are initialised, and `MaybeUninit<T>` has the same
+        // layout as `T`
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr().cast::<T>(), self.len) }
+    }
+    /// Gets a mutable slice of the elements in this [`ArrayVec<T, Cap>`].
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: As above
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.len) }
+    }
+}
+impl<T, Cap: Bitstring> Drop for ArrayVec<T, Cap> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+impl<T, Cap: Bitstring> Default for ArrayVec<T, Cap> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: fmt::Debug, Cap: Bitstring> fmt::Debug for ArrayVec<T, Cap> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+#[test]
+fn array_vec() {
+    use crate::{B0, bs};
+
+    let mut vec = ArrayVec::<u32, bs!(1, 1)>::new();
+    assert!(vec.is_empty());
+    assert_eq!(ArrayVec::<u32, bs!(1, 1)>::capacity(), 3);
+    for i in 0..3 {
+        assert_eq!(vec.push(i), Ok(()));
+    }
+    assert!(vec.is_full());
+    assert_eq!(vec.push(3), Err(3));
+    assert_eq!(vec.as_slice(), &[0, 1, 2]);
+
+    vec.as_mut_slice()[1] = 10;
+    assert_eq!(vec.pop(), Some(2));
+    assert_eq!(vec.pop(), Some(10));
+    assert_eq!(vec.len(), 1);
+    assert_eq!(format!("{vec:?}"), "[0]");
+    assert_eq!(vec.push(4), Ok(()));
+    assert_eq!(vec.as_slice(), &[0, 4]);
+    vec.clear();
+    assert_eq!(vec.pop(), None);
+
+    let mut empty = ArrayVec::<u32, B0>::default();
+    assert!(empty.is_full());
+    assert_eq!(empty.push(1), Err(1));
+    assert!(empty.as_slice().is_empty());
+}
+
+#[test]
+fn drop_array_vec() {
+    use super::drop_safety::DropCounter;
+    use crate::bs;
+
+    DropCounter::reset();
+
+    let mut vec = ArrayVec::<DropCounter, bs!(1, 0, 1)>::new();
+    for _ in 0..4 {
+        assert!(vec.push(DropCounter::new()).is_ok());
+    }
+    drop(vec.pop());
+    assert_eq!(DropCounter::live(), 3);
+    // Only the initialised elements should be dropped
+    drop(vec);
+    assert_eq!(DropCounter::live(), 0);
+
+    let mut vec = ArrayVec::<DropCounter, bs!(1, 0)>::new();
+    assert!(vec.push(DropCounter::new()).is_ok());
+    assert!(vec.push(DropCounter::new()).is_ok());
+    // The rejected element is handed back rather than dropped or leaked
+    let rejected = vec.push(DropCounter::new()).unwrap_err();
+    assert_eq!(DropCounter::live(), 3);
+    drop(rejected);
+    vec.clear();
+    assert_eq!(DropCounter::live(), 0);
+    drop(vec);
+
+    DropCounter::assert_all_dropped();
+}
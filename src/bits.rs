@@ -1,6 +1,7 @@
 use crate::{
     conditional::{Boolean, False, True},
     conditional_system,
+    gates::BitXor,
 };
 use std::marker::PhantomData;
 
@@ -26,7 +27,7 @@ mod sealed {
 
 /// A trait for single bits, implemented by [`B0`] and [`B1`] only. This trait is sealed to prevent
 /// external implementations.
-pub trait Bit: sealed::SealedBit {
+pub trait Bit: sealed::SealedBit + Default {
     /// Returns the `AND` of this bit with the given one.
     type And<Other: Bit>: Bit;
     /// Returns the `OR` of this bit with the given one.
@@ -35,6 +36,8 @@ pub trait Bit: sealed::SealedBit {
     type Not: Bit;
 
     const UNSIGNED: usize;
+    /// The value of this bit as a boolean, which is `true` for [`B1`] and `false` for [`B0`].
+    const BIT: bool;
 
     /// Returns an internal representation of the boolean value arising from this bit. This is used
     /// internally for type-level conditionals, and generally shouldn't be interacted with by
@@ -50,6 +53,7 @@ impl Bit for B1 {
     type Not = B0;
 
     const UNSIGNED: usize = 1;
+    const BIT: bool = true;
 
     type Bool = True;
 
@@ -61,6 +65,7 @@ impl Bit for B0 {
     type Not = B1;
 
     const UNSIGNED: usize = 0;
+    const BIT: bool = false;
 
     type Bool = False;
 
@@ -68,13 +73,16 @@ impl Bit for B0 {
 }
 
 /// A trait for bitstrings of arbitrary length. This is implemented for any [`Bit`] and
-/// [`Tape<H, B>`].
-pub trait Bitstring: IsB0 + sealed::SealedBitstring {
+/// [`Tape<H, B>`]. All bitstrings are zero-sized and implement [`Default`], so instances of them
+/// can be freely created.
+pub trait Bitstring: IsB0 + sealed::SealedBitstring + Default {
     /// The head of the bitstring, which is itself another bitstring.
     type Head: Bitstring;
     /// The least-significant bit of the bitstring.
     type Lsb: Bit;
 
+    /// The value of this bitstring as a `usize`. Using this for a bitstring whose value doesn't
+    /// fit in a `usize` is a compile-time error.
     const UNSIGNED: usize;
 
     /// A "trimmed" version of this bitstring, which will have no leading zeroes.
@@ -84,11 +92,45 @@ pub trait Bitstring: IsB0 + sealed::SealedBitstring {
     type And<Other: Bitstring>: Bitstring;
     /// The bitwise `OR` of this bitstring with the given one.
     type Or<Other: Bitstring>: Bitstring;
+    /// The bitwise `XOR` of this bitstring with the given one.
+    type Xor<Other: Bitstring>: Bitstring;
     /// The bitwise `NOT` of this bitstring.
     type Not: Bitstring;
 
-    /// Returns a string representation of this bitstring, for debugging.
+    /// Returns a string representation of this bitstring, for debugging. This is the *literal*
+    /// layout of the type, so any leading zeroes in it will be rendered too (e.g. `bs!(0, 1, 0)`
+    /// renders as `010`). See [`Self::render_trimmed`] for rendering just the value.
+    ///
+    /// There's no such thing as an empty bitstring, since every bitstring is built from at least
+    /// one [`Bit`], so this never returns an empty string. In particular, [`B0`] renders as `0`,
+    /// and an untrimmed zero like `bs!(0, 0)` renders as `00`, but both of these trim to [`B0`].
     fn render() -> String;
+    /// Returns a string representation of the *value* of this bitstring, without any leading
+    /// zeroes (e.g. `bs!(0, 1, 0)` renders as `10`). This is the same as rendering
+    /// [`Self::Trimmed`]. Zero always renders as `0`.
+    fn render_trimmed() -> String {
+        Self::Trimmed::render()
+    }
+    /// Returns the same as [`Self::render_trimmed`], along with the number of significant bits in
+    /// this bitstring (see [`BitLength`](crate::BitLength)). Note that zero renders as `0`, but
+    /// has a bit length of 0, so the length won't always match that of the string.
+    fn render_with_len() -> (String, usize) {
+        (
+            Self::render_trimmed(),
+            <crate::BitLength<Self> as Bitstring>::UNSIGNED,
+        )
+    }
+    /// Returns a string representation of the *value* of this bitstring, left-padded with zeroes
+    /// to be `Width::UNSIGNED` characters long (e.g. `bs!(1, 0, 1)` padded to a width of
+    /// `bs!(1, 0, 0)` renders as `0101`). If the value needs more characters than that, it will
+    /// be rendered in full, just like [`Self::render_trimmed`].
+    fn render_padded<Width: Bitstring>() -> String {
+        format!(
+            "{:0>width$}",
+            Self::render_trimmed(),
+            width = Width::UNSIGNED
+        )
+    }
 }
 
 /// A "tape" of bits, represented as a recursive container. The generic parameter `H` is the "head"
@@ -109,7 +151,15 @@ impl<H: Bitstring, B: Bit> Bitstring for Tape<H, B> {
     type Head = H;
     type Lsb = B;
 
-    const UNSIGNED: usize = H::UNSIGNED * 2 + B::UNSIGNED;
+    // This would overflow for bitstrings with more significant bits than a `usize` has, so we
+    // make that a (loud) compile-time error wherever the value is used
+    const UNSIGNED: usize = match H::UNSIGNED.checked_mul(2) {
+        Some(doubled) => match doubled.checked_add(B::UNSIGNED) {
+            Some(value) => value,
+            None => panic!("bitstring value overflows `usize`"),
+        },
+        None => panic!("bitstring value overflows `usize`"),
+    };
 
     // If the trimmed head is zero, then this is the final bit, so we should return just that.
     // Otherwise, return a tape with the trimmed head and this bit. This evaluates recursively.
@@ -123,6 +173,8 @@ impl<H: Bitstring, B: Bit> Bitstring for Tape<H, B> {
     type And<Other: Bitstring> =
         <Tape<H::And<Other::Head>, B::And<Other::Lsb>> as Bitstring>::Trimmed;
     type Or<Other: Bitstring> = <Tape<H::Or<Other::Head>, B::Or<Other::Lsb>> as Bitstring>::Trimmed;
+    type Xor<Other: Bitstring> =
+        <Tape<H::Xor<Other::Head>, BitXor<B, Other::Lsb>> as Bitstring>::Trimmed;
     type Not = <Tape<H::Not, B::Not> as Bitstring>::Trimmed;
 
     // TODO: Compile-time or const way of doing this?
@@ -145,6 +197,8 @@ impl<B: Bit> Bitstring for B {
     // ORing two equal-length tapes, or two tapes where the longer one comes first, will add a
     // leading zero.
     type Or<Other: Bitstring> = <Tape<Other::Head, B::Or<Other::Lsb>> as Bitstring>::Trimmed;
+    // As with OR, our implicit leading zero leaves the other head unchanged
+    type Xor<Other: Bitstring> = <Tape<Other::Head, BitXor<B, Other::Lsb>> as Bitstring>::Trimmed;
     type Not = B::Not;
 
     fn render() -> String {
@@ -152,6 +206,24 @@ impl<B: Bit> Bitstring for B {
     }
 }
 
+// Bitstrings serialize as their values, as plain integers. This can't be a blanket impl over
+// `Bitstring` due to the orphan rules, and there's no deserialization, because the value of a
+// bitstring is determined by its type.
+#[cfg(feature = "serde")]
+macro_rules! impl_serialize {
+    ($([$($generics:tt)*] $ty:ty),+) => {
+        $(
+            impl<$($generics)*> serde::Serialize for $ty {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_u64(<$ty as Bitstring>::UNSIGNED as u64)
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "serde")]
+impl_serialize!([] B0, [] B1, [H: Bitstring, B: Bit] Tape<H, B>);
+
 /// A type alias for our internal conditional, which will evaluate to `T` if the input bit is
 /// [`B0`], and `F` otherwise.
 ///
@@ -197,7 +269,120 @@ fn bitstrings() {
     assert_eq!(And::<T10, T101>::render(), "0");
     assert_eq!(Or::<T101, T10>::render(), "111");
     assert_eq!(And::<T101, T10>::render(), "0");
+    assert_eq!(Xor::<T10, T101>::render(), "111");
+    assert_eq!(Xor::<T101, T10>::render(), "111");
+    assert_eq!(Xor::<T101, B1>::render(), "100");
+    assert_eq!(Xor::<crate::bs!(1, 0, 0), T10>::render(), "110");
+    assert_eq!(Xor::<T101, T101>::render(), "0");
 
     type T910 = crate::bs!(1, 1, 1, 0, 0, 0, 1, 1, 1, 0);
     assert_eq!(T910::UNSIGNED, 910);
 }
+
+#[test]
+fn render_trimmed() {
+    use crate::bs;
+
+    assert_eq!(<bs!(0, 1, 0)>::render(), "010");
+    assert_eq!(<bs!(0, 1, 0)>::render_trimmed(), "10");
+    assert_eq!(<bs!(0, 0, 1, 1)>::render(), "0011");
+    assert_eq!(<bs!(0, 0, 1, 1)>::render_trimmed(), "11");
+    assert_eq!(<bs!(0, 0, 0)>::render(), "000");
+    assert_eq!(<bs!(0, 0, 0)>::render_trimmed(), "0");
+    assert_eq!(<bs!(1, 0, 1)>::render_trimmed(), "101");
+    assert_eq!(B0::render_trimmed(), "0");
+}
+
+#[test]
+fn render_zeroes() {
+    use crate::{Not, Xor, bs};
+
+    assert_eq!(B0::render(), "0");
+    assert_eq!(<bs!(0)>::render(), "0");
+    assert_eq!(<bs!(0, 0)>::render(), "00");
+    assert_eq!(<Tape<bs!(0, 0), B0>>::render(), "000");
+
+    type Zero4 = Tape<bs!(0, 0, 0), B0>;
+    type Five = bs!(1, 0, 1);
+
+    // Every zero trims to exactly `B0`, which these will only compile if they do
+    let _: <bs!(0, 0) as Bitstring>::Trimmed = B0;
+    let _: <Zero4 as Bitstring>::Trimmed = B0;
+    let _: Not<bs!(1, 1)> = B0;
+    let _: Xor<Five, Five> = B0;
+    for rendered in [
+        B0::render_trimmed(),
+        <bs!(0, 0)>::render_trimmed(),
+        Zero4::render_trimmed(),
+        Not::<bs!(1, 1)>::render(),
+        Xor::<Five, Five>::render(),
+    ] {
+        assert_eq!(rendered, "0");
+    }
+
+    assert_eq!(<bs!(0, 0)>::render_with_len(), ("0".to_string(), 0));
+    assert_eq!(<bs!(0, 0)>::render_padded::<bs!(1, 1)>(), "000");
+    assert_eq!(B0::render_padded::<B0>(), "0");
+}
+
+#[test]
+fn render_with_len() {
+    use crate::bs;
+
+    assert_eq!(<bs!(1, 0, 1)>::render_with_len(), ("101".to_string(), 3));
+    assert_eq!(<bs!(0, 0, 1, 1)>::render_with_len(), ("11".to_string(), 2));
+    assert_eq!(B1::render_with_len(), ("1".to_string(), 1));
+    assert_eq!(B0::render_with_len(), ("0".to_string(), 0));
+    assert_eq!(<bs!(0, 0)>::render_with_len(), ("0".to_string(), 0));
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn unsigned_full_width() {
+    use crate::bs;
+
+    // The largest value that fits, with exactly as many bits as a `usize` (any more is a compile
+    // error, see `tests/ui/unsigned_overflow.rs`)
+    type Largest = bs!(
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1
+    );
+    assert_eq!(<Largest as Bitstring>::UNSIGNED, usize::MAX);
+}
+
+#[test]
+fn render_padded() {
+    use crate::bs;
+
+    assert_eq!(<bs!(1, 0, 1)>::render_padded::<bs!(1, 0, 0)>(), "0101");
+    assert_eq!(
+        <bs!(1, 0, 1)>::render_padded::<bs!(1, 0, 0, 0)>(),
+        "00000101"
+    );
+    assert_eq!(<bs!(0, 0, 1, 1)>::render_padded::<bs!(1, 1, 0)>(), "000011");
+    assert_eq!(B0::render_padded::<bs!(1, 0)>(), "00");
+    // Exact width
+    assert_eq!(<bs!(1, 0, 1)>::render_padded::<bs!(1, 1)>(), "101");
+    assert_eq!(<bs!(0, 1, 0, 1)>::render_padded::<bs!(1, 1)>(), "101");
+    // Over width
+    assert_eq!(<bs!(1, 0, 1, 1, 0)>::render_padded::<bs!(1, 0)>(), "10110");
+    assert_eq!(B1::render_padded::<B0>(), "1");
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize() {
+    use crate::bs;
+
+    assert_eq!(
+        serde_json::to_string(&<bs!(1, 0, 1)>::default()).unwrap(),
+        "5"
+    );
+    assert_eq!(
+        serde_json::to_string(&<bs!(0, 0, 1, 1)>::default()).unwrap(),
+        "3"
+    );
+    assert_eq!(serde_json::to_string(&B0).unwrap(), "0");
+    assert_eq!(serde_json::to_string(&B1).unwrap(), "1");
+}
@@ -0,0 +1,111 @@
+//! Type-level three-way comparison. [`Cmp<A, B>`] resolves to one of the marker types [`Less`],
+//! [`Equal`], or [`Greater`], which can then be switched on with [`SelectOrdering`], much like
+//! matching on an [`Ordering`]:
+//!
+//! ```
+//! use typebits::{bs, cmp::{Cmp, SelectOrdering}};
+//!
+//! struct Below;
+//! struct Same;
+//! struct Above;
+//! type Describe<A, B> = SelectOrdering<Cmp<A, B>, Below, Same, Above>;
+//!
+//! let _: Describe<bs!(1, 0), bs!(1, 1)> = Below;
+//! let _: Describe<bs!(1, 1), bs!(0, 1, 1)> = Same;
+//! let _: Describe<bs!(1, 0, 0), bs!(1, 1)> = Above;
+//! ```
+
+use crate::{Bit, Boolean, Lt, SameValue};
+use std::cmp::Ordering;
+
+/// The type-level equivalent of [`Ordering::Less`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Less;
+/// The type-level equivalent of [`Ordering::Equal`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Equal;
+/// The type-level equivalent of [`Ordering::Greater`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Greater;
+
+mod sealed {
+    /// A private trait used to make sure the user can't add more orderings.
+    pub trait SealedOrdering {}
+    impl SealedOrdering for super::Less {}
+    impl SealedOrdering for super::Equal {}
+    impl SealedOrdering for super::Greater {}
+}
+
+/// A trait for the type-level orderings [`Less`], [`Equal`], and [`Greater`].
+pub trait OrderingMarker: sealed::SealedOrdering {
+    /// Picks the first type given if this is [`Less`], the second if it's [`Equal`], and the
+    /// third if it's [`Greater`]. Like [`Boolean::Select`], this has no bounds on its arms.
+    type Select<L, E, G>;
+
+    /// The runtime [`Ordering`] this represents.
+    const ORDERING: Ordering;
+}
+impl OrderingMarker for Less {
+    type Select<L, E, G> = L;
+
+    const ORDERING: Ordering = Ordering::Less;
+}
+impl OrderingMarker for Equal {
+    type Select<L, E, G> = E;
+
+    const ORDERING: Ordering = Ordering::Equal;
+}
+impl OrderingMarker for Greater {
+    type Select<L, E, G> = G;
+
+    const ORDERING: Ordering = Ordering::Greater;
+}
+
+/// Compares the values of the two given bitstrings, resolving to [`Less`] if `A < B`, [`Equal`]
+/// if they have the same value (ignoring leading zeroes), and [`Greater`] if `A > B`.
+pub type Cmp<A /*: Bytes*/, B /*: Bytes*/> = <<Lt<A, B> as Bit>::Bool as Boolean>::Select<
+    Less,
+    <<SameValue<A, B> as Bit>::Bool as Boolean>::Select<Equal, Greater>,
+>;
+/// A three-arm switch on the given [`OrderingMarker`], which produces `L` for [`Less`], `E` for
+/// [`Equal`], and `G` for [`Greater`].
+pub type SelectOrdering<O, L, E, G> = <O as OrderingMarker>::Select<L, E, G>;
+
+#[test]
+fn cmp() {
+    use crate::{B0, B1, bs};
+
+    type T5 = bs!(1, 0, 1);
+    type T6 = bs!(1, 1, 0);
+    type T5Untrimmed = bs!(0, 1, 0, 1);
+
+    let _: Cmp<T5, T6> = Less;
+    let _: Cmp<B0, B1> = Less;
+    let _: Cmp<T5, T5Untrimmed> = Equal;
+    let _: Cmp<B0, bs!(0, 0)> = Equal;
+    let _: Cmp<T6, T5Untrimmed> = Greater;
+    let _: Cmp<B1, B0> = Greater;
+
+    assert_eq!(<Cmp<bs!(1, 0), bs!(1, 1)>>::ORDERING, 2.cmp(&3));
+    assert_eq!(<Cmp<bs!(1, 1), bs!(1, 1)>>::ORDERING, Ordering::Equal);
+    assert_eq!(<Cmp<bs!(1, 1), bs!(1, 0)>>::ORDERING, 3.cmp(&2));
+}
+
+#[test]
+fn select_ordering() {
+    use crate::{B0, Bitstring, bs};
+
+    // Clamp to the range `[2, 5]`, with a three-arm switch for each bound
+    type Clamp<X> = SelectOrdering<
+        Cmp<X, bs!(1, 0)>,
+        bs!(1, 0),
+        bs!(1, 0),
+        SelectOrdering<Cmp<X, bs!(1, 0, 1)>, X, bs!(1, 0, 1), bs!(1, 0, 1)>,
+    >;
+
+    assert_eq!(<Clamp<B0> as Bitstring>::UNSIGNED, 2);
+    assert_eq!(<Clamp<bs!(1, 0)> as Bitstring>::UNSIGNED, 2);
+    assert_eq!(<Clamp<bs!(1, 1)> as Bitstring>::UNSIGNED, 3);
+    assert_eq!(<Clamp<bs!(1, 0, 1)> as Bitstring>::UNSIGNED, 5);
+    assert_eq!(<Clamp<bs!(1, 1, 1, 1)> as Bitstring>::UNSIGNED, 5);
+}
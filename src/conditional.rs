@@ -1,3 +1,5 @@
+use crate::{B0, B1, Bit, GetBit};
+
 /// A global value indicating truth.
 pub struct True;
 /// A global value indicating falsehood.
@@ -14,6 +16,11 @@ mod sealed {
 /// booleans we use for conditionals. Unfortunately, precisely what those downstream booleans are
 /// need to be statically known here, until we have specialisation to convert with fallbacks.
 pub trait Boolean: sealed::SealedBoolean {
+    /// Picks one of the two given types based on this boolean. Unlike the conditional systems,
+    /// this has no bounds on its branches, and so can't be used for recursion, but it can select
+    /// between arbitrary types.
+    type Select<Then, Else>;
+
     type And<Other: Boolean>: Boolean;
     type Or<Other: Boolean>: Boolean;
     type Not: Boolean;
@@ -25,6 +32,8 @@ pub trait Boolean: sealed::SealedBoolean {
     type ArrayBoolean: crate::array::array_conditionals::Boolean;
 }
 impl Boolean for True {
+    type Select<Then, Else> = Then;
+
     type And<Other: Boolean> = Other;
     type Or<Other: Boolean> = True;
     type Not = False;
@@ -36,6 +45,8 @@ impl Boolean for True {
     type ArrayBoolean = crate::array::array_conditionals::True;
 }
 impl Boolean for False {
+    type Select<Then, Else> = Else;
+
     type And<Other: Boolean> = False;
     type Or<Other: Boolean> = Other;
     type Not = True;
@@ -47,9 +58,50 @@ impl Boolean for False {
     type ArrayBoolean = crate::array::array_conditionals::False;
 }
 
+/// Picks one of four types based on the value of the given bitstring selector, so a selector of
+/// `0` will produce `T0`, `1` will produce `T1`, etc. Only the lowest two bits of the selector are
+/// considered, so larger selectors will wrap around.
+pub type Dispatch4<Sel /*: Bytes*/, T0, T1, T2, T3> =
+    <<GetBit<Sel, B1> as Bit>::Bool as Boolean>::Select<
+        <<GetBit<Sel, B0> as Bit>::Bool as Boolean>::Select<T3, T2>,
+        <<GetBit<Sel, B0> as Bit>::Bool as Boolean>::Select<T1, T0>,
+    >;
+
 /// Creates a conditional system with the given visibility and bounds. This will produce a module
 /// of the given name (e.g. `conditional_system!(pub my_conditionals, MyBound)`). The bounds will
 /// be applied to the outputs of any conditional.
+///
+/// This can be used from other crates as well, and the generated code only refers to items at the
+/// root of this crate. Note that the bounds are resolved from inside the generated module, so they
+/// should be given as full paths (e.g. `crate::MyBound`). Conditions can be built from the global
+/// booleans (e.g. [`Bit::Bool`]) with the generated `Local` alias, although in generic code this
+/// needs a `FromGlobal` bound on the global boolean:
+///
+/// ```
+/// use typebits::{B0, B1, Bit, conditional_system};
+///
+/// pub trait Named {
+///     const NAME: &'static str;
+/// }
+/// pub struct Yes;
+/// impl Named for Yes {
+///     const NAME: &'static str = "yes";
+/// }
+/// pub struct No;
+/// impl Named for No {
+///     const NAME: &'static str = "no";
+/// }
+///
+/// conditional_system!(named_conditionals, crate::Named);
+/// use named_conditionals::{Local, SimpleIf};
+///
+/// type Answer<B> = SimpleIf<Local<<B as Bit>::Bool>, Yes, No>;
+///
+/// fn main() {
+///     assert_eq!(<Answer<B1>>::NAME, "yes");
+///     assert_eq!(<Answer<B0>>::NAME, "no");
+/// }
+/// ```
 #[macro_export]
 macro_rules! conditional_system {
     ($vis:vis $name:ident $(, $($data_bounds:tt)+)?) => {
@@ -71,20 +123,51 @@ macro_rules! conditional_system {
                 /// [`Lazy`] in a strategy for avoiding immediate evaluation by the compiler.
                 type Select<Then: Lazy, Else: Lazy>: Lazy;
 
+                /// The logical `AND` of this boolean with the given one.
+                type And<Other: Boolean>: Boolean;
+                /// The logical `OR` of this boolean with the given one.
+                type Or<Other: Boolean>: Boolean;
+                /// The logical `NOT` of this boolean.
+                type Not: Boolean;
+
                 /// An associated type that takes us *back* to the global boolean types.
-                type GlobalBoolean: $crate::conditional::Boolean;
+                type GlobalBoolean: $crate::Boolean;
             }
             impl Boolean for True {
                 type Select<Then: Lazy, Else: Lazy> = Then;
 
-                type GlobalBoolean = $crate::conditional::True;
+                type And<Other: Boolean> = Other;
+                type Or<Other: Boolean> = True;
+                type Not = False;
+
+                type GlobalBoolean = $crate::True;
             }
             impl Boolean for False {
                 type Select<Then: Lazy, Else: Lazy> = Else;
 
-                type GlobalBoolean = $crate::conditional::False;
+                type And<Other: Boolean> = False;
+                type Or<Other: Boolean> = Other;
+                type Not = True;
+
+                type GlobalBoolean = $crate::False;
             }
 
+            /// A trait that takes the global boolean types into this system's ones, the inverse
+            /// of [`Boolean::GlobalBoolean`]. This lets conditions computed elsewhere (like
+            /// [`Bit::Bool`]($crate::Bit::Bool)) be used in this system.
+            pub trait FromGlobal: $crate::Boolean {
+                /// The system-specific version of this global boolean.
+                type Local: Boolean;
+            }
+            impl FromGlobal for $crate::True {
+                type Local = True;
+            }
+            impl FromGlobal for $crate::False {
+                type Local = False;
+            }
+            /// Converts the given global boolean into this system's version of it.
+            pub type Local<G> = <G as FromGlobal>::Local;
+
             /// A trait used as a hack to delay evaluation. This must be unique to each conditional
             /// system, as the bounds on the associated output type define where the system is
             /// generically useful.
@@ -111,3 +194,40 @@ macro_rules! conditional_system {
         }
     };
 }
+
+#[test]
+fn local_boolean_logic() {
+    use crate::conditionals::bitstring::{Boolean, False, If, Thunk, True};
+    use crate::{B0, B1, Bitstring};
+
+    type Check<Cond> = If<Cond, Thunk<B1>, Thunk<B0>>;
+
+    assert_eq!(Check::<<True as Boolean>::And<False>>::render(), "0");
+    assert_eq!(Check::<<True as Boolean>::And<True>>::render(), "1");
+    assert_eq!(Check::<<False as Boolean>::Or<True>>::render(), "1");
+    assert_eq!(Check::<<False as Boolean>::Or<False>>::render(), "0");
+    assert_eq!(Check::<<False as Boolean>::Not>::render(), "1");
+    assert_eq!(
+        Check::<<<True as Boolean>::Not as Boolean>::Or<<True as Boolean>::And<True>>>::render(),
+        "1"
+    );
+}
+
+#[test]
+fn dispatch4() {
+    use crate::{Tape, bs};
+
+    struct Zero;
+    struct One;
+    struct Two;
+    struct Three;
+    type Dispatch<Sel> = Dispatch4<Sel, Zero, One, Two, Three>;
+
+    // These will only compile if each selector resolves to the right type
+    let _: Dispatch<B0> = Zero;
+    let _: Dispatch<B1> = One;
+    let _: Dispatch<bs!(1, 0)> = Two;
+    let _: Dispatch<bs!(1, 1)> = Three;
+    // Untrimmed selectors are fine
+    let _: Dispatch<Tape<bs!(0, 0), B1>> = One;
+}
@@ -0,0 +1,132 @@
+use crate::{Array, Bitstring, Quotient, Sum, bs};
+use std::fmt;
+
+/// The number of bytes needed to store `Width` flags.
+type FlagBytes<Width> = Quotient<Sum<Width, bs!(1, 1, 1)>, bs!(1, 0, 0, 0)>;
+
+/// A runtime set of `Width::UNSIGNED` boolean flags, stored as a bitfield of exactly as many
+/// bytes as needed. Flags are accessed by compile-time indices (which are bitstrings), so indexing
+/// past the width of the set will fail to compile:
+///
+/// ```compile_fail
+/// # use typebits::{FlagSet, bs};
+/// let mut flags = FlagSet::<bs!(1, 0, 1)>::new();
+/// flags.set::<bs!(1, 0, 1)>();
+/// ```
+///
+/// The flags are stored little-endian, so the flag at index 0 is the least-significant bit of the
+/// first byte.
+pub struct FlagSet<Width: Bitstring> {
+    bytes: Array<u8, FlagBytes<Width>>,
+}
+impl<Width: Bitstring> FlagSet<Width> {
+    /// Creates a new [`FlagSet<Width>`] with no flags set.
+    pub fn new() -> Self {
+        Self {
+            bytes: Array::new(),
+        }
+    }
+
+    /// Sets the flag at index `I`.
+    pub fn set<I: Bitstring>(&mut self) {
+        let (byte, mask) = Self::locate::<I>();
+        self.bytes[byte] |= mask;
+    }
+    /// Clears the flag at index `I`.
+    pub fn clear<I: Bitstring>(&mut self) {
+        let (byte, mask) = Self::locate::<I>();
+        self.bytes[byte] &= !mask;
+    }
+    /// Returns whether or not the flag at index `I` is set.
+    pub fn test<I: Bitstring>(&self) -> bool {
+        let (byte, mask) = Self::locate::<I>();
+        self.bytes[byte] & mask != 0
+    }
+
+    /// Returns whether or not no flags at all are set.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.iter().all(|&byte| byte == 0)
+    }
+    /// Gets the underlying bytes of this [`FlagSet<Width>`] (see the type-level documentation
+    /// for their layout).
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Returns the number of flags in this [`FlagSet<Width>`], which is equal to
+    /// [`Width::UNSIGNED`].
+    pub const fn width() -> usize {
+        Width::UNSIGNED
+    }
+
+    /// Gets the byte index and bit mask of the flag at index `I`, failing to compile if the index
+    /// is out of bounds.
+    const fn locate<I: Bitstring>() -> (usize, u8) {
+        const { assert!(I::UNSIGNED < Width::UNSIGNED, "flag index out of bounds") };
+
+        (I::UNSIGNED / 8, 1 << (I::UNSIGNED % 8))
+    }
+}
+impl<Width: Bitstring> Default for FlagSet<Width> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<Width: Bitstring> Clone for FlagSet<Width> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+impl<Width: Bitstring> PartialEq for FlagSet<Width> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+impl<Width: Bitstring> Eq for FlagSet<Width> {}
+impl<Width: Bitstring> fmt::Debug for FlagSet<Width> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Print the flags like a bitstring, with the highest index first
+        let flags: String = (0..Width::UNSIGNED)
+            .rev()
+            .map(|i| match self.bytes[i / 8] & (1 << (i % 8)) {
+                0 => '0',
+                _ => '1',
+            })
+            .collect();
+        f.debug_tuple("FlagSet").field(&flags).finish()
+    }
+}
+
+#[test]
+fn flag_set() {
+    use crate::{B0, B1};
+
+    let mut flags = FlagSet::<bs!(1, 0, 1, 0)>::new();
+    assert_eq!(FlagSet::<bs!(1, 0, 1, 0)>::width(), 10);
+    assert_eq!(flags.as_bytes().len(), 2);
+    assert!(flags.is_empty());
+
+    flags.set::<B1>();
+    flags.set::<bs!(1, 0, 0, 1)>();
+    assert!(flags.test::<B1>());
+    assert!(flags.test::<bs!(1, 0, 0, 1)>());
+    assert!(!flags.test::<B0>());
+    assert!(!flags.test::<bs!(1, 0, 0, 0)>());
+    assert_eq!(flags.as_bytes(), &[0b10, 0b10]);
+    assert_eq!(format!("{flags:?}"), "FlagSet(\"1000000010\")");
+
+    let before = flags.clone();
+    flags.clear::<B1>();
+    assert!(!flags.test::<B1>());
+    assert!(flags.test::<bs!(1, 0, 0, 1)>());
+    assert_ne!(flags, before);
+    flags.clear::<bs!(1, 0, 0, 1)>();
+    assert!(flags.is_empty());
+    assert_eq!(flags, FlagSet::default());
+
+    // Exact multiples of a byte don't need any more space
+    assert_eq!(FlagSet::<bs!(1, 0, 0, 0)>::new().as_bytes().len(), 1);
+    assert!(FlagSet::<B0>::new().as_bytes().is_empty());
+}
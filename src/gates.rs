@@ -1,4 +1,8 @@
-use crate::{Bit, bits::Bitstring};
+use crate::{
+    B0, B1, Bit, Dec, SameValue, Shl, Tape, Truncate,
+    bits::{Bitstring, IsB0},
+    conditionals::bitstring::SimpleIf,
+};
 
 /// Returns the bitwise `AND` of the two given bitstrings.
 ///
@@ -23,7 +27,7 @@ pub type Not<A> = <A as Bitstring>::Not;
 /// Note that this is designed to work for [`Bytes`], not individual bits! However, as bits can be
 /// interpreted as bytes, you can use this for single bits as well, though it may not give the
 /// desired output, and you should consider [`BitXor`] instead.
-pub type Xor<A, B> = Or<And<A, Not<B>>, And<Not<A>, B>>;
+pub type Xor<A, B> = <A as Bitstring>::Xor<B>;
 // Returns the bitwise `NAND` of the two given bitstrings.
 ///
 /// Note that this is designed to work for [`Bytes`], not individual bits! However, as bits can be
@@ -58,8 +62,164 @@ pub type BitNor<A, B> = BitNot<BitOr<A, B>>;
 /// Returns the single-bit `XNOR` of the two given bits.
 pub type BitXnor<A, B> = BitNot<BitXor<A, B>>;
 
+/// Returns the majority of the three given bits, which is [`B1`](crate::B1) if at least two of
+/// them are set. This is exactly the carry-out of a full adder (the `HalfAdd::Carry` used
+/// internally by [`Sum`](crate::Sum)), with `C` as the carry-in. See also [`maj3`].
+pub type Maj3<A, B, C> = BitOr<BitOr<BitAnd<A, B>, BitAnd<A, C>>, BitAnd<B, C>>;
+/// Returns the parity of the three given bits, which is [`B1`](crate::B1) if an odd number of them
+/// are set. This is the sum bit of a full adder. See also [`parity3`].
+pub type Parity3<A, B, C> = BitXor<BitXor<A, B>, C>;
+
+/// Returns the majority of three booleans. This is a `const` mirror of [`Maj3`].
+pub const fn maj3(a: bool, b: bool, c: bool) -> bool {
+    (a && (b || c)) || (b && c)
+}
+/// Returns the parity of three booleans. This is a `const` mirror of [`Parity3`].
+pub const fn parity3(a: bool, b: bool, c: bool) -> bool {
+    a ^ b ^ c
+}
+
 /// A two-bit multiplexer. This will return `A` if `S` is false, and `B` if `S` is true. If you
 /// need an if statement for types, consider [`crate::conditional_system!`].
 ///
 /// This is designed to work for two single bits.
 pub type BitMux<S, A, B> = BitOr<BitAnd<BitNot<S>, A>, BitAnd<S, B>>;
+
+/// A whole-bitstring multiplexer. This will return `A` if the bit `S` is [`B0`], and `B` if it's
+/// [`B1`](crate::B1).
+pub type Mux<S, A, B> = SimpleIf<<S as IsB0>::BitstringIsB0, A, B>;
+/// A whole-bitstring 1-to-2 demultiplexer, the inverse of [`Mux`]. This returns a
+/// [`Pair`](crate::Pair) of outputs, and routes `In` to the first if the bit `S` is [`B0`], and to
+/// the second if it's [`B1`](crate::B1). Whichever output isn't selected will be [`B0`].
+pub type Demux<S, In> = (Mux<S, In, B0>, Mux<S, B0, In>);
+
+/// Appends the given bit to the end of the given bitstring, making it the new least-significant
+/// bit (so this is equivalent to shifting left by one and setting the last bit). The result is
+/// trimmed, so pushing onto a zero bitstring will just give the pushed bit.
+pub type PushBit<B /*: Bytes*/, Lsb /*: Bit*/> = <Tape<B, Lsb> as Bitstring>::Trimmed;
+
+/// Returns [`B1`] if the lowest `Width` bits of the given bitstring are all set, and [`B0`]
+/// otherwise. Any bits past `Width` are ignored, and every bitstring is all ones in its lowest
+/// zero bits. See also [`is_all_ones`].
+pub type IsAllOnes<B /*: Bytes*/, Width /*: Bytes*/> =
+    SameValue<Truncate<B, Width>, Dec<Shl<B1, Width>>>;
+
+/// Returns a `Width`-bit mask with each bit set where `A` and `B` agree, and cleared where they
+/// differ. This is the bitwise `XNOR` of the two, but both are zero-extended (or truncated) to
+/// `Width` bits first, so the leading bits of differing-length bitstrings are compared properly.
+/// Unlike [`SameValue`], this compares bit by bit rather than giving a single bit.
+pub type BitwiseEq<A /*: Bytes*/, B /*: Bytes*/, Width /*: Bytes*/> =
+    Xor<Truncate<Xor<A, B>, Width>, Dec<Shl<B1, Width>>>;
+
+/// Returns whether or not the lowest `Width` bits of `B` are all set. This is a `const` mirror of
+/// [`IsAllOnes`].
+pub const fn is_all_ones<B: Bitstring, Width: Bitstring>() -> bool {
+    <IsAllOnes<B, Width> as Bit>::BIT
+}
+
+#[test]
+fn mux() {
+    use crate::{First, Second, bs};
+
+    assert_eq!(Mux::<B0, bs!(1, 0), bs!(1, 1)>::render(), "10");
+    assert_eq!(Mux::<B1, bs!(1, 0), bs!(1, 1)>::render(), "11");
+
+    assert_eq!(First::<Demux<B0, bs!(1, 0, 1)>>::render(), "101");
+    assert_eq!(Second::<Demux<B0, bs!(1, 0, 1)>>::render(), "0");
+    assert_eq!(First::<Demux<B1, bs!(1, 0, 1)>>::render(), "0");
+    assert_eq!(Second::<Demux<B1, bs!(1, 0, 1)>>::render(), "101");
+}
+
+#[test]
+fn push_bit() {
+    use crate::bs;
+
+    assert_eq!(PushBit::<bs!(1, 0), B1>::render(), "101");
+    assert_eq!(PushBit::<bs!(1, 0), B0>::render(), "100");
+    assert_eq!(PushBit::<B1, B1>::render(), "11");
+    assert_eq!(PushBit::<B0, B1>::render(), "1");
+    assert_eq!(PushBit::<B0, B0>::render(), "0");
+    assert_eq!(PushBit::<bs!(0, 0, 1), B0>::render(), "10");
+    assert_eq!(PushBit::<PushBit<B1, B0>, B1>::render(), "101");
+}
+
+#[test]
+fn maj3_parity3() {
+    assert_eq!(<Maj3<B0, B0, B0> as Bit>::RENDER, "0");
+    assert_eq!(<Maj3<B0, B0, B1> as Bit>::RENDER, "0");
+    assert_eq!(<Maj3<B0, B1, B0> as Bit>::RENDER, "0");
+    assert_eq!(<Maj3<B0, B1, B1> as Bit>::RENDER, "1");
+    assert_eq!(<Maj3<B1, B0, B0> as Bit>::RENDER, "0");
+    assert_eq!(<Maj3<B1, B0, B1> as Bit>::RENDER, "1");
+    assert_eq!(<Maj3<B1, B1, B0> as Bit>::RENDER, "1");
+    assert_eq!(<Maj3<B1, B1, B1> as Bit>::RENDER, "1");
+
+    assert_eq!(<Parity3<B0, B0, B0> as Bit>::RENDER, "0");
+    assert_eq!(<Parity3<B0, B0, B1> as Bit>::RENDER, "1");
+    assert_eq!(<Parity3<B0, B1, B0> as Bit>::RENDER, "1");
+    assert_eq!(<Parity3<B0, B1, B1> as Bit>::RENDER, "0");
+    assert_eq!(<Parity3<B1, B0, B0> as Bit>::RENDER, "1");
+    assert_eq!(<Parity3<B1, B0, B1> as Bit>::RENDER, "0");
+    assert_eq!(<Parity3<B1, B1, B0> as Bit>::RENDER, "0");
+    assert_eq!(<Parity3<B1, B1, B1> as Bit>::RENDER, "1");
+
+    assert!(!maj3(false, false, false));
+    assert!(!maj3(false, false, true));
+    assert!(!maj3(false, true, false));
+    assert!(maj3(false, true, true));
+    assert!(!maj3(true, false, false));
+    assert!(maj3(true, false, true));
+    assert!(maj3(true, true, false));
+    assert!(maj3(true, true, true));
+
+    assert!(!parity3(false, false, false));
+    assert!(parity3(false, false, true));
+    assert!(parity3(false, true, false));
+    assert!(!parity3(false, true, true));
+    assert!(parity3(true, false, false));
+    assert!(!parity3(true, false, true));
+    assert!(!parity3(true, true, false));
+    assert!(parity3(true, true, true));
+}
+
+#[test]
+fn all_ones() {
+    use crate::bs;
+
+    assert_eq!(<IsAllOnes<bs!(1, 1, 1), bs!(1, 1)> as Bit>::RENDER, "1");
+    assert_eq!(<IsAllOnes<bs!(1, 0, 1), bs!(1, 1)> as Bit>::RENDER, "0");
+    assert_eq!(<IsAllOnes<bs!(1, 1), bs!(1, 1)> as Bit>::RENDER, "0");
+    // Only the lowest bits matter
+    assert_eq!(<IsAllOnes<bs!(1, 0, 1, 1), bs!(1, 0)> as Bit>::RENDER, "1");
+    assert_eq!(<IsAllOnes<bs!(1, 1, 1, 0), bs!(1, 0)> as Bit>::RENDER, "0");
+    assert_eq!(<IsAllOnes<B1, B1> as Bit>::RENDER, "1");
+    assert_eq!(<IsAllOnes<B0, B1> as Bit>::RENDER, "0");
+    assert_eq!(<IsAllOnes<B0, B0> as Bit>::RENDER, "1");
+
+    const { assert!(is_all_ones::<bs!(1, 1, 1, 1, 1, 1, 1, 1), bs!(1, 0, 0, 0)>()) };
+    const { assert!(!is_all_ones::<bs!(1, 1, 1, 1, 1, 1, 1, 1), bs!(1, 0, 0, 1)>()) };
+}
+
+#[test]
+fn bitwise_eq() {
+    use crate::bs;
+
+    type W3 = bs!(1, 1);
+
+    assert_eq!(BitwiseEq::<bs!(1, 0, 1), bs!(1, 1, 1), W3>::render(), "101");
+    assert_eq!(BitwiseEq::<bs!(1, 0, 1), bs!(1, 0, 1), W3>::render(), "111");
+    assert_eq!(BitwiseEq::<bs!(1, 0, 1), bs!(0, 1, 0), W3>::render(), "0");
+    // Shorter bitstrings are zero-extended
+    assert_eq!(BitwiseEq::<B1, bs!(1, 0, 1), W3>::render(), "11");
+    assert_eq!(BitwiseEq::<B0, B0, bs!(1, 0, 0)>::render(), "1111");
+    assert_eq!(
+        BitwiseEq::<bs!(1, 1), bs!(0, 0, 1, 1), bs!(1, 0, 1)>::render(),
+        "11111"
+    );
+    // Bits past the width are ignored
+    assert_eq!(
+        BitwiseEq::<bs!(1, 0, 1, 1), bs!(0, 0, 1, 1), W3>::render(),
+        "111"
+    );
+    assert_eq!(BitwiseEq::<bs!(1, 0), B1, B0>::render(), "0");
+}
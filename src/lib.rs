@@ -2,15 +2,33 @@ mod arithmetic;
 #[cfg(feature = "array")]
 mod array;
 mod bits;
+pub mod cmp;
 mod conditional;
+#[cfg(feature = "array")]
+mod flags;
 mod gates;
+mod list;
+#[cfg(feature = "matrix")]
+mod matrix;
+mod pair;
 
 pub use arithmetic::*;
+#[cfg(feature = "array_vec")]
+pub use array::ArrayVec;
+#[cfg(feature = "array")]
+#[doc(hidden)]
+pub use array::internal_depth;
 #[cfg(feature = "array")]
-pub use array::Array;
+pub use array::{Array, ArrayIntoIter, Zeroable};
 pub use bits::{B0, B1, Bit, Bitstring, Tape};
-pub use conditional::{Boolean, False, True};
+pub use conditional::{Boolean, Dispatch4, False, True};
+#[cfg(feature = "array")]
+pub use flags::FlagSet;
 pub use gates::*;
+pub use list::{BitList, Cons, Extrema, MaxOf, MinOf, Nil, ProductOf, SumOf};
+#[cfg(feature = "matrix")]
+pub use matrix::Matrix;
+pub use pair::{First, Pair, Second};
 
 /// Types related to our internal bitwise conditional system. This is used to implement bitwise
 /// recursion for arithmetic, and may be of use to others, though this is far from a generic
@@ -30,6 +48,19 @@ pub mod conditionals {
     }
 }
 
+/// The most commonly used parts of this crate, which can be imported all at once with
+/// `use typebits::prelude::*`. This includes the core bit traits and types, the arithmetic,
+/// comparison, and gate aliases, the bitstring macros, and [`Array`] (if enabled).
+pub mod prelude {
+    #[cfg(feature = "array")]
+    pub use crate::Array;
+    pub use crate::{And, Not, Or, Xor};
+    pub use crate::{B0, B1, Bit, Bitstring, Tape};
+    pub use crate::{Diff, Pow, Product, Quotient, Remainder, Shl, Shr, Sum};
+    pub use crate::{Ge, Gt, Le, Lt, Max, Min, SameValue};
+    pub use crate::{bitstring, bs, max_bits, min_bits};
+}
+
 /// Convenience macro for constructing tapes of bits. This accepts syntax like `$crate::bitstring!(1, 0, 1)` to
 /// produce `Tape<Tape<B1, B0>, B1>`.
 #[macro_export]
@@ -55,3 +86,104 @@ macro_rules! bitstring {
 }
 
 pub use bitstring as bs;
+
+/// Returns the largest of the given bitstring types (trimmed), by folding [`Max`] across them. For
+/// example, `max_bits!(bs!(1, 0), bs!(1, 1, 1), bs!(1, 1))` produces `bs!(1, 1, 1)`.
+#[macro_export]
+macro_rules! max_bits {
+    ($only:ty $(,)?) => { <$only as $crate::Bitstring>::Trimmed };
+    ($first:ty, $($rest:ty),+ $(,)?) => { $crate::Max<$first, $crate::max_bits!($($rest),+)> };
+}
+
+/// Returns the smallest of the given bitstring types (trimmed), by folding [`Min`] across them.
+/// For example, `min_bits!(bs!(1, 0), bs!(1, 1, 1), bs!(1, 1))` produces `bs!(1, 0)`.
+#[macro_export]
+macro_rules! min_bits {
+    ($only:ty $(,)?) => { <$only as $crate::Bitstring>::Trimmed };
+    ($first:ty, $($rest:ty),+ $(,)?) => { $crate::Min<$first, $crate::min_bits!($($rest),+)> };
+}
+
+/// Builds a [`BitList`] of the given bitstring types, so `bitlist!(A, B, C)` produces
+/// `Cons<A, Cons<B, Cons<C, Nil>>>`, and `bitlist!()` produces [`Nil`].
+#[macro_export]
+macro_rules! bitlist {
+    () => { $crate::Nil };
+    ($first:ty $(, $rest:ty)* $(,)?) => { $crate::Cons<$first, $crate::bitlist!($($rest),*)> };
+}
+
+/// Produces the ASCII decimal digits of the value of the given bitstring type as a `[u8; K]`,
+/// where `K` is the number of digits, so `digits!(bs!(1, 1, 1, 1, 0, 1, 1))` produces
+/// `[b'1', b'2', b'3']`. This can be used in `const` contexts, for embedding numeric labels. See
+/// [`decimal_digits`].
+#[macro_export]
+macro_rules! digits {
+    ($ty:ty) => {
+        $crate::decimal_digits::<
+            $ty,
+            { <$crate::DigitCount<$ty, $crate::bs!(1, 0, 1, 0)> as $crate::Bitstring>::UNSIGNED },
+        >()
+    };
+}
+
+/// Converts a boolean literal into the corresponding bit type, so `bit_type!(true)` produces
+/// [`B1`] and `bit_type!(false)` produces [`B0`]. This is mostly useful in macro-generated code.
+/// The reverse conversion is [`Bit::BIT`].
+#[macro_export]
+macro_rules! bit_type {
+    (true) => {
+        $crate::B1
+    };
+    (false) => {
+        $crate::B0
+    };
+}
+
+/// Like [`bitstring!`], but fails to compile if every bit given is zero, guaranteeing that the
+/// resulting bitstring has a non-zero value. This is useful for things like widths and array
+/// lengths, where zero would be meaningless. For example, `nonzero_bits!(1, 0, 1)` produces the
+/// same type as `bs!(1, 0, 1)`, but `nonzero_bits!(0, 0)` is a compile error.
+#[macro_export]
+macro_rules! nonzero_bits {
+    // Internal: scan for a set bit, keeping the original bits to hand off once we find one
+    (@scan [$($bits:tt),+] 1 $(, $rest:tt)*) => { $crate::bitstring!($($bits),+) };
+    (@scan [$($bits:tt),+] B1 $(, $rest:tt)*) => { $crate::bitstring!($($bits),+) };
+    (@scan [$($bits:tt),+] 0 $(, $rest:tt)*) => { $crate::nonzero_bits!(@scan [$($bits),+] $($rest),*) };
+    (@scan [$($bits:tt),+] B0 $(, $rest:tt)*) => { $crate::nonzero_bits!(@scan [$($bits),+] $($rest),*) };
+    (@scan [$($bits:tt),+]) => {
+        ::core::compile_error!("`nonzero_bits!` needs at least one set bit")
+    };
+
+    // Public entry
+    ($($bit:tt),+ $(,)?) => { $crate::nonzero_bits!(@scan [$($bit),+] $($bit),+) };
+}
+
+#[test]
+fn max_min_bits() {
+    type Largest = max_bits!(bs!(1, 0), bs!(1, 1, 1), bs!(1, 1));
+    type Smallest = min_bits!(bs!(1, 0), bs!(1, 1, 1), bs!(1, 1));
+
+    assert_eq!(Largest::render(), "111");
+    assert_eq!(Smallest::render(), "10");
+    assert_eq!(<max_bits!(bs!(0, 1, 0))>::render(), "10");
+    assert_eq!(<min_bits!(bs!(1, 0, 1), B0, bs!(1, 1),)>::render(), "0");
+    assert_eq!(
+        <max_bits!(B1, bs!(0, 0, 1), B0, bs!(0, 1, 1))>::render(),
+        "11"
+    );
+}
+
+#[test]
+fn bit_type() {
+    let _: bit_type!(true) = B1;
+    let _: bit_type!(false) = B0;
+
+    const { assert!(<bit_type!(true) as Bit>::BIT) };
+    const { assert!(!<bit_type!(false) as Bit>::BIT) };
+}
+
+#[test]
+fn nonzero_bits() {
+    assert_eq!(<nonzero_bits!(1, 0, 1)>::UNSIGNED, 5);
+    assert_eq!(<nonzero_bits!(0, 0, B1)>::UNSIGNED, 1);
+    assert_eq!(<nonzero_bits!(1) as Bitstring>::UNSIGNED, 1);
+}
@@ -0,0 +1,115 @@
+use crate::{B0, B1, Bitstring, Max, Min};
+use std::marker::PhantomData;
+
+/// Returns the largest of the bitstrings in the given non-empty [`BitList`] (trimmed).
+pub type MaxOf<L /*: BitList*/> = <L as Extrema>::Max;
+/// Returns the smallest of the bitstrings in the given non-empty [`BitList`] (trimmed).
+pub type MinOf<L /*: BitList*/> = <L as Extrema>::Min;
+/// Returns the sum of all the bitstrings in the given [`BitList`], which is zero for [`Nil`].
+pub type SumOf<L /*: BitList*/> = <L as BitList>::Sum;
+/// Returns the product of all the bitstrings in the given [`BitList`], which is one for [`Nil`].
+pub type ProductOf<L /*: BitList*/> = <L as BitList>::Product;
+
+/// The empty [`BitList`].
+pub struct Nil;
+/// A [`BitList`] made of the bitstring `H` followed by the list `T`. These are most easily built
+/// with [`bitlist!`](crate::bitlist).
+pub struct Cons<H: Bitstring, T: BitList> {
+    _phantom: PhantomData<(H, T)>,
+}
+
+mod sealed {
+    /// A private trait used to make sure the user can't add more kinds of lists.
+    pub trait SealedBitList {}
+    impl SealedBitList for super::Nil {}
+    impl<H: crate::Bitstring, T: super::BitList> SealedBitList for super::Cons<H, T> {}
+}
+
+/// A type-level list of bitstrings, which is either [`Nil`] or a [`Cons`] of a bitstring and
+/// another list.
+pub trait BitList: sealed::SealedBitList {
+    /// The number of bitstrings in this list.
+    const LEN: usize;
+
+    /// The largest of the given bitstring and every bitstring in this list.
+    type MaxWith<Acc: Bitstring>: Bitstring;
+    /// The smallest of the given bitstring and every bitstring in this list.
+    type MinWith<Acc: Bitstring>: Bitstring;
+
+    /// The sum of every bitstring in this list.
+    type Sum: Bitstring;
+    /// The product of every bitstring in this list.
+    type Product: Bitstring;
+}
+impl BitList for Nil {
+    const LEN: usize = 0;
+
+    type MaxWith<Acc: Bitstring> = Acc::Trimmed;
+    type MinWith<Acc: Bitstring> = Acc::Trimmed;
+
+    // The identities of addition and multiplication
+    type Sum = B0;
+    type Product = B1;
+}
+impl<H: Bitstring, T: BitList> BitList for Cons<H, T> {
+    const LEN: usize = T::LEN + 1;
+
+    type MaxWith<Acc: Bitstring> = T::MaxWith<Max<Acc, H>>;
+    type MinWith<Acc: Bitstring> = T::MinWith<Min<Acc, H>>;
+
+    type Sum = crate::Sum<H, T::Sum>;
+    type Product = crate::Product<H, T::Product>;
+}
+
+/// A trait for the extrema of non-empty [`BitList`]s. The empty list has no smallest element, so
+/// this is only implemented for [`Cons`] lists.
+pub trait Extrema: BitList {
+    /// The largest bitstring in this list.
+    type Max: Bitstring;
+    /// The smallest bitstring in this list.
+    type Min: Bitstring;
+}
+impl<H: Bitstring, T: BitList> Extrema for Cons<H, T> {
+    // Start the fold with the first element, which is as good as any other
+    type Max = T::MaxWith<H>;
+    type Min = T::MinWith<H>;
+}
+
+#[test]
+fn bit_list() {
+    use crate::{bitlist, bs};
+
+    type List = bitlist!(bs!(1, 0), bs!(1, 1, 1), bs!(0, 1, 1));
+
+    assert_eq!(MaxOf::<List>::render(), "111");
+    assert_eq!(MinOf::<List>::render(), "10");
+    assert_eq!(<List as BitList>::LEN, 3);
+
+    assert_eq!(MaxOf::<bitlist!(bs!(0, 1, 0))>::render(), "10");
+    assert_eq!(MinOf::<bitlist!(bs!(0, 1, 0))>::render(), "10");
+    assert_eq!(MinOf::<bitlist!(B1, B0, bs!(1, 0),)>::render(), "0");
+    assert_eq!(<bitlist!() as BitList>::LEN, 0);
+}
+
+#[test]
+fn bit_list_sum_product() {
+    use crate::{bitlist, bs};
+
+    type List = bitlist!(bs!(1, 0), bs!(1, 1), bs!(1, 0, 0));
+
+    assert_eq!(SumOf::<List>::render(), "1001");
+    assert_eq!(ProductOf::<List>::render(), "11000");
+    assert_eq!(SumOf::<bitlist!(bs!(0, 1, 1))>::render(), "11");
+    assert_eq!(ProductOf::<bitlist!(bs!(0, 1, 1))>::render(), "11");
+    assert_eq!(
+        <SumOf<bitlist!(B1, B1, B1, B1, B1, bs!(1, 0, 1, 0))> as Bitstring>::UNSIGNED,
+        15
+    );
+    assert_eq!(
+        ProductOf::<bitlist!(bs!(1, 1), B0, bs!(1, 1))>::render(),
+        "0"
+    );
+    // The empty list gives the identities
+    assert_eq!(SumOf::<bitlist!()>::render(), "0");
+    assert_eq!(ProductOf::<bitlist!()>::render(), "1");
+}
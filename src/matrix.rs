@@ -0,0 +1,176 @@
+use crate::{Array, Bitstring, Product, Quotient};
+use std::mem::ManuallyDrop;
+
+/// A row-major matrix with `Rows` rows and `Cols` columns, both of which are bitstrings. This is a
+/// thin wrapper over an [`Array`] of length `Rows * Cols`, and so it has exactly the same
+/// in-memory representation as `[T; Rows * Cols]`.
+#[repr(transparent)]
+pub struct Matrix<T, Rows: Bitstring, Cols: Bitstring> {
+    data: Array<T, Product<Rows, Cols>>,
+}
+impl<T, Rows: Bitstring, Cols: Bitstring> Matrix<T, Rows, Cols> {
+    /// Creates a new [`Matrix<T, Rows, Cols>`] from the given array of its elements in row-major
+    /// order (i.e. the first `Cols::UNSIGNED` elements are the first row, etc.).
+    pub const fn from_array(data: Array<T, Product<Rows, Cols>>) -> Self {
+        Self { data }
+    }
+    /// Converts this [`Matrix<T, Rows, Cols>`] into an array of its elements in row-major order.
+    pub fn into_array(self) -> Array<T, Product<Rows, Cols>> {
+        self.data
+    }
+    /// Gets the elements of this [`Matrix<T, Rows, Cols>`] as an array in row-major order.
+    pub const fn as_array(&self) -> &Array<T, Product<Rows, Cols>> {
+        &self.data
+    }
+    /// Gets the elements of this [`Matrix<T, Rows, Cols>`] as a mutable array in row-major order.
+    pub const fn as_array_mut(&mut self) -> &mut Array<T, Product<Rows, Cols>> {
+        &mut self.data
+    }
+
+    /// Returns the number of rows in this [`Matrix<T, Rows, Cols>`], which is equal to
+    /// [`Rows::UNSIGNED`].
+    pub const fn rows() -> usize {
+        Rows::UNSIGNED
+    }
+    /// Returns the number of columns in this [`Matrix<T, Rows, Cols>`], which is equal to
+    /// [`Cols::UNSIGNED`].
+    pub const fn cols() -> usize {
+        Cols::UNSIGNED
+    }
+
+    /// Gets the element at the given row and column, or `None` if either is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= Rows::UNSIGNED || col >= Cols::UNSIGNED {
+            return None;
+        }
+
+        self.data.as_slice().get(row * Cols::UNSIGNED + col)
+    }
+    /// Gets a mutable reference to the element at the given row and column, or `None` if either
+    /// is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= Rows::UNSIGNED || col >= Cols::UNSIGNED {
+            return None;
+        }
+
+        self.data.as_mut_slice().get_mut(row * Cols::UNSIGNED + col)
+    }
+
+    /// Transposes this [`Matrix<T, Rows, Cols>`], so its rows become columns and vice versa. This
+    /// moves the elements without cloning them.
+    pub fn transpose(self) -> Matrix<T, Cols, Rows> {
+        let (rows, cols) = (Rows::UNSIGNED, Cols::UNSIGNED);
+
+        // We're moving every element out, so the original must never drop them
+        let data = ManuallyDrop::new(self.data);
+        let src = data.as_slice().as_ptr();
+        let mut uninit = Array::<T, Product<Cols, Rows>>::uninit();
+        let dst = uninit.as_mut_slice();
+        for row in 0..rows {
+            for col in 0..cols {
+                // SAFETY: Both matrices have `rows * cols` elements, so this is in bounds, and
+                // each element is read exactly once
+                dst[col * rows + row].write(unsafe { src.add(row * cols + col).read() });
+            }
+        }
+
+        Matrix {
+            // SAFETY: We've just written every element
+            data: unsafe { uninit.assume_init() },
+        }
+    }
+}
+impl<T, N: Bitstring> Array<T, N> {
+    /// Reinterprets this [`Array<T, N>`] as a row-major [`Matrix`] with `Cols` columns, so the
+    /// first `Cols::UNSIGNED` elements become the first row, etc. This doesn't move any elements
+    /// around. `N` must be a multiple of `Cols`, otherwise this will fail to compile:
+    ///
+    /// ```compile_fail
+    /// # use typebits::{Array, bs};
+    /// let array = Array::<u8, bs!(1, 1, 1)>::new();
+    /// let matrix = array.into_matrix::<bs!(1, 1)>();
+    /// ```
+    pub fn into_matrix<Cols: Bitstring>(self) -> Matrix<T, Quotient<N, Cols>, Cols> {
+        const {
+            assert!(
+                Cols::UNSIGNED != 0 && N::UNSIGNED % Cols::UNSIGNED == 0,
+                "array length must be a multiple of the number of columns"
+            )
+        };
+
+        // The elements are moved into the new array, so the original must never drop them
+        let data = ManuallyDrop::new(self);
+        // SAFETY: We've checked that `Quotient<N, Cols> * Cols` is exactly `N`, and both arrays
+        // have the same in-memory representation as `[T; N::UNSIGNED]`
+        Matrix::from_array(unsafe {
+            (&*data as *const Self)
+                .cast::<Array<T, Product<Quotient<N, Cols>, Cols>>>()
+                .read()
+        })
+    }
+}
+
+#[test]
+fn matrix() {
+    use crate::bs;
+
+    type M = Matrix<u8, bs!(1, 0), bs!(1, 1)>;
+
+    let mut matrix = M::from_array(Array::new_from_slice(&[1, 2, 3, 4, 5, 6]));
+    assert_eq!(M::rows(), 2);
+    assert_eq!(M::cols(), 3);
+    assert_eq!(matrix.get(0, 0), Some(&1));
+    assert_eq!(matrix.get(0, 2), Some(&3));
+    assert_eq!(matrix.get(1, 0), Some(&4));
+    assert_eq!(matrix.get(1, 2), Some(&6));
+    assert_eq!(matrix.get(2, 0), None);
+    assert_eq!(matrix.get(0, 3), None);
+
+    *matrix.get_mut(1, 1).unwrap() = 0;
+    assert_eq!(matrix.as_array().as_slice(), &[1, 2, 3, 4, 0, 6]);
+    assert!(matrix.get_mut(2, 2).is_none());
+}
+
+#[test]
+fn matrix_transpose() {
+    use crate::bs;
+
+    let matrix = Matrix::<String, bs!(1, 0), bs!(1, 1)>::from_array(Array::new_from_slice(
+        &["a", "b", "c", "d", "e", "f"].map(String::from),
+    ));
+    let transposed = matrix.transpose();
+    assert_eq!(Matrix::<String, bs!(1, 1), bs!(1, 0)>::rows(), 3);
+    assert_eq!(
+        transposed.as_array().as_slice(),
+        ["a", "d", "b", "e", "c", "f"]
+    );
+    for (row, col, elem) in [(0, 1, "d"), (1, 0, "b"), (2, 1, "f")] {
+        assert_eq!(transposed.get(row, col).unwrap(), elem);
+    }
+    assert_eq!(
+        transposed.transpose().into_array().as_slice(),
+        ["a", "b", "c", "d", "e", "f"]
+    );
+}
+
+#[test]
+fn array_into_matrix() {
+    use crate::bs;
+
+    let array = Array::<String, bs!(1, 1, 0)>::new_from_slice(
+        &["a", "b", "c", "d", "e", "f"].map(String::from),
+    );
+    let matrix = array.into_matrix::<bs!(1, 1)>();
+    assert_eq!(Matrix::<String, bs!(1, 0), bs!(1, 1)>::rows(), 2);
+    for (row, col, elem) in [(0, 0, "a"), (0, 2, "c"), (1, 0, "d"), (1, 2, "f")] {
+        assert_eq!(matrix.get(row, col).unwrap(), elem);
+    }
+    assert!(matrix.get(2, 0).is_none());
+    assert_eq!(
+        matrix.into_array().as_slice(),
+        ["a", "b", "c", "d", "e", "f"]
+    );
+
+    let column = Array::<u8, bs!(1, 1)>::new_from_slice(&[1, 2, 3]).into_matrix::<crate::B1>();
+    assert_eq!(column.get(2, 0), Some(&3));
+}
@@ -0,0 +1,18 @@
+/// Returns the first element of the given [`Pair`].
+pub type First<P> = <P as Pair>::First;
+/// Returns the second element of the given [`Pair`].
+pub type Second<P> = <P as Pair>::Second;
+
+/// A trait for type-level pairs, which are used by operations that produce two outputs (e.g. a
+/// result and a flag). Pairs are just tuples of two types, and this trait lets you pull the
+/// elements back out of them.
+pub trait Pair {
+    /// The first element of the pair.
+    type First;
+    /// The second element of the pair.
+    type Second;
+}
+impl<A, B> Pair for (A, B) {
+    type First = A;
+    type Second = B;
+}
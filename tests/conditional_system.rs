@@ -0,0 +1,55 @@
+//! Makes sure `conditional_system!` works from outside this crate, with a custom bound.
+
+use typebits::{B0, B1, Bit, Bitstring, Boolean, bs, conditional_system, conditionals::IsB0};
+
+/// A type-level unary counter, which we use as a bound that has nothing to do with bitstrings.
+pub trait Peano {
+    const VALUE: usize;
+}
+pub struct Zero;
+impl Peano for Zero {
+    const VALUE: usize = 0;
+}
+pub struct Succ<P: Peano>(std::marker::PhantomData<P>);
+impl<P: Peano> Peano for Succ<P> {
+    const VALUE: usize = P::VALUE + 1;
+}
+
+conditional_system!(pub peano_conditionals, crate::Peano);
+use peano_conditionals::{If, Lazy, Local, SimpleIf, Thunk};
+
+/// Adds two to the given counter, lazily, so we can check `If` with something other than a
+/// `Thunk`.
+pub struct AddTwo<P: Peano>(std::marker::PhantomData<P>);
+impl<P: Peano> Lazy for AddTwo<P> {
+    type Output = Succ<Succ<P>>;
+}
+
+#[test]
+fn external_simple_if() {
+    type Pick<B> = SimpleIf<Local<<B as Bit>::Bool>, Succ<Zero>, Zero>;
+
+    assert_eq!(<Pick<B1>>::VALUE, 1);
+    assert_eq!(<Pick<B0>>::VALUE, 0);
+}
+
+#[test]
+fn external_if() {
+    type Step<B> = If<Local<<B as Bit>::Bool>, AddTwo<Zero>, Thunk<Succ<Zero>>>;
+    // Conditions can come from anything that produces a global boolean
+    type IsZero<B> =
+        If<Local<<<B as Bitstring>::Trimmed as IsB0>::GlobalIsB0>, Thunk<Zero>, AddTwo<Succ<Zero>>>;
+
+    assert_eq!(<Step<B1>>::VALUE, 2);
+    assert_eq!(<Step<B0>>::VALUE, 1);
+    assert_eq!(<IsZero<bs!(0, 0)>>::VALUE, 0);
+    assert_eq!(<IsZero<bs!(1, 0, 1)>>::VALUE, 3);
+}
+
+#[test]
+fn external_global_round_trip() {
+    use peano_conditionals::{False, True};
+
+    const { assert!(<<True as peano_conditionals::Boolean>::GlobalBoolean as Boolean>::BOOL) };
+    const { assert!(!<<False as peano_conditionals::Boolean>::GlobalBoolean as Boolean>::BOOL) };
+}
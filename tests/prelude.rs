@@ -0,0 +1,16 @@
+use typebits::prelude::*;
+
+#[test]
+fn prelude_only() {
+    type Five = Sum<bs!(1, 1), bs!(1, 0)>;
+    assert_eq!(Five::render(), "101");
+    assert_eq!(<Five as Bitstring>::UNSIGNED, 5);
+    assert_eq!(<Gt<Five, bs!(1, 0, 0)> as Bit>::UNSIGNED, 1);
+
+    #[cfg(feature = "array")]
+    {
+        let array = Array::<u8, Five>::new_from_slice(&[1, 2, 3, 4, 5]);
+        assert_eq!(Array::<u8, Five>::len(), 5);
+        assert_eq!(array.iter().sum::<u8>(), 15);
+    }
+}
@@ -0,0 +1,6 @@
+use typebits::{assert_array_size, bs};
+
+// This is actually 20 bytes
+assert_array_size!(u32, bs!(1, 0, 1), 16);
+
+fn main() {}
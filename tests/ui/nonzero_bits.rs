@@ -0,0 +1,5 @@
+use typebits::nonzero_bits;
+
+type Zero = nonzero_bits!(0, 0);
+
+fn main() {}
@@ -0,0 +1,17 @@
+use typebits::{Bitstring, Tape, bs};
+
+// 65 significant bits, which is one too many for a 64-bit `usize`
+type Wide = Tape<
+    bs!(
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0
+    ),
+    typebits::B0,
+>;
+
+const VALUE: usize = <Wide as Bitstring>::UNSIGNED;
+
+fn main() {
+    println!("{VALUE}");
+}